@@ -2,10 +2,10 @@
 $ rustc constants.rs
 $ ./constants
 100000
-5
+1
 3.14159
 true
 🦀
-(5, 3.14159, true, '🦀')
-[5, 5, 5]
+(1, 3.14159, true, '🦀')
+[1, 1, 1]
 86400
@@ -6,8 +6,19 @@
 // these from other programming languages. Let's take a look at each one in more
 // detail.
 
+use std::convert::TryFrom;
+use std::env;
+use std::hint::black_box;
+
 fn main() {
 
+    // `./scalar-types overflow` only runs the `overflow` function from the
+    // *Overflow* section below.
+    if env::args().nth(1).as_deref() == Some("overflow") {
+        overflow();
+        return;
+    }
+
     // Signed integer types: represents positive, negative, and zero values
     let int_i8: i8 = -8;        // -2^7 to 2^7 - 1
     let int_i16: i16 = -16;     // -2^15 to 2^15 - 1
@@ -82,4 +93,140 @@ fn main() {
     println!("c: {}", c);
     println!("z: {}", z);
     println!("heart_eyed_cat: {}", heart_eyed_cat);
+
+    // ### Limits
+
+    // Each integer type has `MIN` and `MAX` constants for the smallest and
+    // largest value it can hold, and `BITS` for its size in bits.
+    println!("i32: {} to {}, {} bits", i32::MIN, i32::MAX, i32::BITS);
+    println!("u8: {} to {}, {} bits", u8::MIN, u8::MAX, u8::BITS);
+    println!("i8: {} to {}", i8::MIN, i8::MAX);
+
+    // ### Overflow
+
+    // When the result of an operation doesn't fit in the type, the integer
+    // *overflows*. How that's handled depends on the build: in debug builds
+    // the program panics, and in release builds, like `rustc -O`, the value
+    // silently wraps around. Running this program with the argument
+    // `overflow` calls this function, which shows either, depending on how
+    // it was compiled. The compiler rejects overflows it can detect at
+    // compile time, so `black_box` hides the value `1` from it.
+    fn overflow() {
+        let checks = if cfg!(debug_assertions) {
+            "enabled"
+        } else {
+            "disabled"
+        };
+        println!("Overflow checks: {}", checks);
+        let one = black_box(1u8);
+        println!("255 + 1 = {}", u8::MAX + one);
+    }
+
+    // To get the same behavior in every build, the integer types have
+    // methods that say how to handle overflow:
+    // 1. `checked_*` returns `None` on overflow, like the `checked_mul` in
+    //    the error handling chapter.
+    // 2. `wrapping_*` wraps around, past `MAX` back to `MIN`.
+    // 3. `saturating_*` stops at `MAX` or `MIN`.
+    // 4. `overflowing_*` wraps around, and also returns whether it did.
+    let big: u8 = 250;
+    println!(
+        "checked: {:?} {:?}",
+        big.checked_add(5),
+        big.checked_add(10)
+    );
+    println!("wrapping: {} {}", big.wrapping_add(10), 0u8.wrapping_sub(1));
+    println!(
+        "saturating: {} {}",
+        big.saturating_add(10),
+        5u8.saturating_sub(10)
+    );
+    println!(
+        "overflowing: {:?} {:?}",
+        big.overflowing_add(5),
+        big.overflowing_add(10)
+    );
+
+    // Signed integers can overflow in surprising places: `i32::MIN` has no
+    // positive counterpart, so even negating it overflows.
+    println!("-i32::MIN: {:?}", i32::MIN.checked_neg());
+    println!("|i8::MIN|: {}", i8::MIN.saturating_abs());
+
+    // ### Casting with `as`
+
+    // `as` converts between numeric types. It never fails, which means it
+    // can quietly change the value:
+    // 1. Casting to a smaller integer type keeps only the lowest bits.
+    // 2. Casting between signed and unsigned types of the same size keeps
+    //    the bits and reinterprets them, so negative numbers become large
+    //    ones.
+    // 3. Casting a float to an integer rounds toward zero and saturates at
+    //    the limits of the type.
+    println!("300 as u8: {}", 300i32 as u8);
+    println!("-1 as u8: {}", -1i32 as u8);
+    println!("200u8 as i8: {}", 200u8 as i8);
+    println!("-1i8 as u32: {}", -1i8 as u32);
+    println!("3.99 as i32: {}", 3.99f64 as i32);
+    println!("-3.99 as i32: {}", -3.99f64 as i32);
+    println!("1e10 as i32: {}", 1e10f64 as i32);
+
+    // `u8` and `char` convert into each other with `as`. A `u8` is always a
+    // valid `char`, but casting a `char` to `u8` truncates code points above
+    // 255, like the `ℤ` from above, which is U+2124.
+    let letter = 97u8 as char;
+    println!("97 as char: {}", letter);
+    println!("{} as u8: {}", letter, letter as u8);
+    println!("{} as u32: {}, as u8: {}", z, z as u32, z as u8);
+
+    // ### `From` and `TryFrom`
+
+    // Conversions that can't lose information implement `From`, so they
+    // can't go wrong. Conversions that can fail implement `TryFrom`, which
+    // returns a `Result` instead of changing the value like `as` does.
+    let widened = i64::from(int_i32);
+    let float = f64::from(float_f32);
+    println!("i64::from: {}, f64::from: {}", widened, float);
+    println!("u8::try_from(200): {:?}", u8::try_from(200i32));
+    println!("u8::try_from(300): {:?}", u8::try_from(300i32));
+    match u32::try_from(-5i64) {
+        Ok(value) => println!("Converted to {}", value),
+        Err(err) => println!("Can't convert -5 to u32: {}", err),
+    }
+
+    // ### Special Floats
+
+    // Floats have values for infinity and "not a number" (NaN), which come
+    // out of operations like dividing by zero, instead of panicking.
+    let zero: f64 = 0.0;
+    let infinity = 1.0 / zero;
+    let nan = 0.0 / zero;
+    let other_nan = 0.0 / zero;
+    println!(
+        "1 / 0 = {}, -1 / 0 = {}, 0 / 0 = {}",
+        infinity,
+        -1.0 / zero,
+        nan
+    );
+    println!(
+        "is_infinite: {}, is_nan: {}",
+        infinity.is_infinite(),
+        nan.is_nan()
+    );
+
+    // NaN isn't equal to anything, not even itself, and isn't smaller or
+    // greater than anything either. That's why floats only implement
+    // `PartialOrd`, and a `Vec<f64>` can't be sorted with `sort`.
+    println!("NaN == NaN: {}", nan == other_nan);
+    println!("NaN < 1: {}, NaN > 1: {}", nan < 1.0, nan > 1.0);
+
+    // Casting NaN to an integer gives 0.
+    println!("NaN as i32: {}", nan as i32);
+
+    // `total_cmp` defines a total order for floats, so `sort_by` can use it.
+    // NaN has a sign bit too, which is why `f64::NAN` is used here: a
+    // positive NaN sorts after infinity, and a negative one before negative
+    // infinity. The sign of a NaN computed by `0.0 / 0.0` isn't specified.
+    let mut floats = vec![2.5, f64::NAN, -1.0, infinity, 0.0];
+    floats.sort_by(|a, b| a.total_cmp(b));
+    println!("Sorted: {:?}", floats);
 }
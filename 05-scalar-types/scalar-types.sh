@@ -26,3 +26,49 @@ boolean_false: false
 c: z
 z: ℤ
 heart_eyed_cat: 😻
+i32: -2147483648 to 2147483647, 32 bits
+u8: 0 to 255, 8 bits
+i8: -128 to 127
+checked: Some(255) None
+wrapping: 4 255
+saturating: 255 0
+overflowing: (255, false) (4, true)
+-i32::MIN: None
+|i8::MIN|: 127
+300 as u8: 44
+-1 as u8: 255
+200u8 as i8: -56
+-1i8 as u32: 4294967295
+3.99 as i32: 3
+-3.99 as i32: -3
+1e10 as i32: 2147483647
+97 as char: a
+a as u8: 97
+ℤ as u32: 8484, as u8: 36
+i64::from: -32, f64::from: 2
+u8::try_from(200): Ok(200)
+u8::try_from(300): Err(TryFromIntError(()))
+Can't convert -5 to u32: out of range integral type conversion attempted
+1 / 0 = inf, -1 / 0 = -inf, 0 / 0 = NaN
+is_infinite: true, is_nan: true
+NaN == NaN: false
+NaN < 1: false, NaN > 1: false
+NaN as i32: 0
+Sorted: [-1.0, 0.0, 2.5, inf, NaN]
+
+# In a debug build, overflow panics. The program exits with the status 101.
+$ ./scalar-types overflow
+Overflow checks: enabled
+# {stderr}
+
+# {match} thread 'main'* panicked at scalar-types.rs:122:34:
+thread 'main' panicked at scalar-types.rs:122:34:
+attempt to add with overflow
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+# {exit} 101
+
+# In a release build, built with `-O`, the value wraps around.
+$ rustc -O scalar-types.rs
+$ ./scalar-types overflow
+Overflow checks: disabled
+255 + 1 = 0
@@ -8,11 +8,17 @@ Alice's age is 44
 Bob's age is not available
 Charlie's's age is 36
 Charlie's age is 36
+# {unordered}
 Alice: 44
 Charlie: 36
+# {end}
+# {unordered}
 Alice
 Charlie
+# {end}
+# {unordered}
 45
 37
+# {end}
 The number of elements are 2
 Is empty? false
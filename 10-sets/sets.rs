@@ -6,7 +6,7 @@
 // around `HashMap<T, ()>`. The `()` is a zero-sized type, which means that
 // each value in the HashMap is just a key with no associated value.
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 fn main() {
     // Creating a new set
@@ -32,27 +32,30 @@ fn main() {
     // Checking if a value is in the set by using `contains()`
     println!("Contains 1: {}", a.contains(&1));
 
-    // `HashSet` supports whole-set operations like `union`,
+    // `HashSet` supports whole-set operations like `union`. Each of them
+    // returns an iterator over the elements, which come out in no particular
+    // order. To print them the same way every time, we collect them into a
+    // `BTreeSet`, which keeps its elements sorted.
     println!(
-        "Union: {:?}", a.union(&b).collect::<HashSet<&i32>>()
+        "Union: {:?}", a.union(&b).collect::<BTreeSet<&i32>>()
     );
 
     // `intersection`,
     println!(
         "Intersection: {:?}",
-        a.intersection(&b).collect::<HashSet<&i32>>()
+        a.intersection(&b).collect::<BTreeSet<&i32>>()
     );
 
     // `difference`,
     println!(
         "Difference: {:?}",
-        a.difference(&b).collect::<HashSet<&i32>>()
+        a.difference(&b).collect::<BTreeSet<&i32>>()
     );
 
     // and `symmetric difference`
     println!(
         "Symmetric Difference: {:?}",
-        a.symmetric_difference(&b).collect::<HashSet<&i32>>()
+        a.symmetric_difference(&b).collect::<BTreeSet<&i32>>()
     );
 
     // `HashSet` also supports three methods for testing relationships between
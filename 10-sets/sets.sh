@@ -2,12 +2,14 @@
 $ rustc sets.rs
 $ ./sets
 Contains 1: true
-Union: {1, 3, 2, 4}
+Union: {1, 3, 4, 5}
 Intersection: {3}
 Difference: {1}
-Symmetric Difference: {2, 1, 4}
+Symmetric Difference: {1, 4, 5}
 Is a subset of: false
 Is a superset of: false
 Is disjoint: false
+# {unordered}
 1
 3
+# {end}
@@ -1,5 +1,5 @@
 # Language: shell
-$ rustc if-else.sh
+$ rustc if-else.rs
 $ ./if-else
 true
 n is between 0 and 100
@@ -3,30 +3,207 @@
 //
 // Rust has support for pattern matching through the `match` statement. It is
 // somewhat similar to the `switch` statement in other languages.
+//
+// Patterns are much more powerful than the cases of a `switch`, though. They
+// can take values apart, or *destructure* them, and bind their parts to
+// variables. The arms of a `match` are tried from top to bottom, and the
+// compiler checks that together they cover every possible value.
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+enum Color {
+    Rgb(u8, u8, u8),
+    Hsv(u16, u8, u8),
+}
+
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(Color),
+}
 
 fn main() {
 
+    // ### Matching Values
+
     // Match statements are similar to switch statements in other languages.
-    let value = 1;
-    match value {
-        // Match with a single pattern.
-        1 => println!("one"),
+    // Each value below is matched by a different arm.
+    for value in [1, 3, 4, 15, 8, -1] {
+        match value {
+            // Match with a single pattern.
+            1 => println!("one"),
+
+            // Match with multiple patterns.
+            2 | 3 => println!("two or three"),
+
+            // Match with a range. `3` is in the range as well, but it was
+            // already matched by the arm above.
+            2..=5 => println!("two through five"),
 
-        // Match with multiple patterns.
-        2 | 3 => println!("two or three"),
+            // Match with a binding. The `@` operator is used to create a
+            // binding for the value that matched the pattern.
+            x @ 12..=20 => println!("twelve through twenty: {}", x),
+
+            // Match with a guard. A guard is an additional condition
+            // that must be true for the pattern to match. It has to come
+            // after the range above, which it would otherwise shadow.
+            x if x > 5 => println!("greater than five: {}", x),
+
+            // Match with a wildcard. The `_` pattern matches anything.
+            _ => println!("something else"),
+        }
+    }
+
+    // ### Destructuring Tuples
+
+    // A tuple pattern matches each element of a tuple against a pattern of
+    // its own. Literals must be equal, and names bind to the element.
+    for point in [(0, 7), (3, 0), (2, 2), (4, -1)] {
+        match point {
+            (0, y) => println!("on the y axis at y = {}", y),
+            (x, 0) => println!("on the x axis at x = {}", x),
+            (x, y) if x == y => println!("on the diagonal at {}", x),
+            (x, y) => println!("somewhere else: ({}, {})", x, y),
+        }
+    }
+
+    // ### Destructuring Structs
+
+    // A struct pattern names the fields to match. `x: 0` matches a field
+    // against a pattern, while a plain `y` binds the field to a variable of
+    // the same name. `..` ignores the remaining fields.
+    let points = [
+        Point { x: 0, y: 5 },
+        Point { x: 3, y: 0 },
+        Point { x: 3, y: 4 },
+    ];
+    for point in &points {
+        match point {
+            Point { x: 0, y } => println!("Point on the y axis at {}", y),
+            Point { x, y: 0 } => println!("Point on the x axis at {}", x),
+            Point { x, .. } => println!("Point with x = {}", x),
+        }
+    }
 
-        // Match with a range.
-        2..=5 => println!("two through five"),
+    // Patterns are not limited to `match`. A `let` statement can destructure
+    // a value too, as long as the pattern always matches. Here the fields
+    // are bound to variables with different names.
+    let Point { x: a, y: b } = points[2];
+    println!("a = {}, b = {}", a, b);
 
-        // Match with a guard. A guard is an additional condition
-        // that must be true for the pattern to match.
-        x if x > 5 => println!("greater than five"),
+    // ### Destructuring Enums
 
-        // Match with a binding. The `@` operator is used to create a binding
-        // for the value that matched the pattern.
-        x @ 12..=20 => println!("twelve through twenty: {}", x),
+    // An enum pattern names the variant, followed by patterns for its
+    // fields. Patterns nest, so the `Color` inside `ChangeColor` can be
+    // destructured in the same arm.
+    let messages = [
+        Message::Move { x: 10, y: -3 },
+        Message::Write(String::from("hello")),
+        Message::ChangeColor(Color::Rgb(255, 128, 0)),
+        Message::ChangeColor(Color::Hsv(200, 50, 80)),
+        Message::Quit,
+    ];
+
+    for message in &messages {
+        match message {
+            Message::Quit => println!("Quit"),
+            Message::Move { x, y } => println!("Move to ({}, {})", x, y),
+            Message::Write(text) => println!("Write \"{}\"", text),
+            Message::ChangeColor(Color::Rgb(r, g, b)) => {
+                println!("Change color to red {}, green {}, blue {}", r, g, b)
+            }
+            Message::ChangeColor(Color::Hsv(h, s, v)) => {
+                println!("Change color to hue {}, saturation {}, value {}", h, s, v)
+            }
+        }
+    }
+
+    // ### Slice Patterns
+
+    // Slices and arrays can be matched by their elements. `..` stands for
+    // any number of elements, and `rest @ ..` binds them to a slice.
+    fn describe(numbers: &[i32]) -> String {
+        match numbers {
+            [] => String::from("empty"),
+            [one] => format!("one element: {}", one),
+            [first, second] => format!("two elements: {} and {}", first, second),
+            [first, .., last] => format!("from {} to {}", first, last),
+        }
+    }
+
+    println!("{}", describe(&[]));
+    println!("{}", describe(&[1]));
+    println!("{}", describe(&[1, 2]));
+    println!("{}", describe(&[1, 2, 3, 4, 5]));
+
+    // Splitting off the first element works well with recursion.
+    fn sum(numbers: &[i32]) -> i32 {
+        match numbers {
+            [] => 0,
+            [first, rest @ ..] => first + sum(rest),
+        }
+    }
+
+    println!("sum = {}", sum(&[1, 2, 3, 4, 5]));
+
+    // ### Nested Patterns
+
+    // Patterns can look several layers deep. Parsing an optional input
+    // gives an `Option<Result<i32, ParseIntError>>`, and a single `match`
+    // handles all of its cases. `n @ 1..=100` checks the range of the
+    // number inside and binds it at the same time.
+    let inputs = [Some("42"), Some("-7"), Some("abc"), None];
+    for input in inputs {
+        match input.map(|text| text.parse::<i32>()) {
+            Some(Ok(n @ 1..=100)) => println!("{} is between 1 and 100", n),
+            Some(Ok(n)) => println!("{} is out of range", n),
+            Some(Err(err)) => println!("not a number: {}", err),
+            None => println!("no input"),
+        }
+    }
+
+    // ### The `matches!` Macro
+
+    // `matches!` returns whether a value matches a pattern, with an
+    // optional guard. It is handy when we only need a `bool`.
+    let text = "pattern matching";
+    let vowels = text
+        .chars()
+        .filter(|&c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u'))
+        .count();
+    println!("\"{}\" has {} vowels", text, vowels);
+
+    let moves_right = messages
+        .iter()
+        .filter(|message| matches!(message, Message::Move { x, .. } if *x > 0))
+        .count();
+    println!("Messages moving right: {}", moves_right);
+
+    // ### `let`-`else`
+
+    // `let`-`else` destructures a value with a pattern that may not match.
+    // When it doesn't, the `else` block runs, which has to leave the current
+    // scope with `return`, `break` or `continue`, or by panicking.
+    // Otherwise the variables are available for the rest of the function,
+    // without the nesting of `match` or `if let`.
+    fn parse_pair(text: &str) -> Result<(i32, i32), String> {
+        let Some((left, right)) = text.split_once(',') else {
+            return Err(String::from("missing comma"));
+        };
+        let (Ok(left), Ok(right)) = (left.trim().parse(), right.trim().parse()) else {
+            return Err(String::from("invalid number"));
+        };
+        Ok((left, right))
+    }
 
-        // Match with a wildcard. The `_` pattern matches anything.
-        _ => println!("something else"),
+    for text in ["3, 4", "3 4", "3, four"] {
+        match parse_pair(text) {
+            Ok((x, y)) => println!("\"{}\" is the pair ({}, {})", text, x, y),
+            Err(err) => println!("\"{}\" is not a pair: {}", text, err),
+        }
     }
 }
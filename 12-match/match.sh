@@ -2,3 +2,35 @@
 $ rustc match.rs
 $ ./match
 one
+two or three
+two through five
+twelve through twenty: 15
+greater than five: 8
+something else
+on the y axis at y = 7
+on the x axis at x = 3
+on the diagonal at 2
+somewhere else: (4, -1)
+Point on the y axis at 5
+Point on the x axis at 3
+Point with x = 3
+a = 3, b = 4
+Move to (10, -3)
+Write "hello"
+Change color to red 255, green 128, blue 0
+Change color to hue 200, saturation 50, value 80
+Quit
+empty
+one element: 1
+two elements: 1 and 2
+from 1 to 5
+sum = 15
+42 is between 1 and 100
+-7 is out of range
+not a number: invalid digit found in string
+no input
+"pattern matching" has 4 vowels
+Messages moving right: 1
+"3, 4" is the pair (3, 4)
+"3 4" is not a pair: missing comma
+"3, four" is not a pair: invalid number
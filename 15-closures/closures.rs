@@ -42,4 +42,169 @@ fn main() {
 
     let answer = call_with_one(|x| x + 2);
     println!("Answer: {}", answer);
+
+    // ### `Fn`, `FnMut` and `FnOnce`
+
+    // How a closure uses its captured variables decides which of the three
+    // closure traits it implements:
+    //
+    // 1. `Fn` closures only read their captures, so they can be called any
+    //    number of times, even at the same time.
+    // 1. `FnMut` closures modify their captures, so calling them needs
+    //    mutable access to the closure.
+    // 1. `FnOnce` closures move a captured value out of themselves, so they
+    //    can only be called once.
+    //
+    // Every `Fn` closure is also `FnMut`, and every closure is `FnOnce`. A
+    // function taking a closure asks for the least it needs.
+
+    // This closure increments `count` each time it's called, so it's
+    // `FnMut`. The closure itself has to be declared `mut` to be called.
+    let mut count = 0;
+    let mut increment = || {
+        count += 1;
+        count
+    };
+    increment();
+    increment();
+    println!("Counter after three calls: {}", increment());
+
+    // The closure holds a mutable borrow of `count` as long as it's used.
+    // Afterwards `count` can be used directly again.
+    println!("count = {}", count);
+
+    // A function that takes an `FnMut` closure has to take it by `mut`.
+    fn call_three_times<F>(mut closure: F)
+    where
+        F: FnMut(),
+    {
+        closure();
+        closure();
+        closure();
+    }
+
+    let mut visits = Vec::new();
+    call_three_times(|| visits.push(visits.len() + 1));
+    println!("Visits: {:?}", visits);
+
+    // This closure returns `name` itself, moving it out of the closure, so
+    // it's only `FnOnce`. Calling it a second time would be a compile
+    // error, because `name` is gone.
+    let name = String::from("Ferris");
+    let take_name = || name;
+    let taken = take_name();
+    println!("Took the name {}", taken);
+
+    fn call_once<F>(closure: F) -> String
+    where
+        F: FnOnce() -> String,
+    {
+        closure()
+    }
+
+    let greeting = String::from("Hello");
+    println!("{}", call_once(move || greeting + ", world"));
+
+    // ### Moving Captures
+
+    // By default a closure borrows the variables it captures, if it can.
+    // The `move` keyword makes it take ownership of them instead. This is
+    // needed when the closure outlives the current scope, like the closure
+    // of a new thread, which may run after the function that spawned it
+    // has returned.
+    let message = String::from("hello from the main thread");
+    let handle = std::thread::spawn(move || {
+        println!("The thread received: {}", message);
+    });
+    handle.join().unwrap();
+
+    // `message` was moved into the closure, so it can't be used here
+    // anymore.
+
+    // ### Returning Closures
+
+    // Each closure has its own unique type, which can't be written down. A
+    // function can return a closure as `impl Fn`, which names the trait
+    // instead of the type. The `move` is required, because `factor` doesn't
+    // live past the end of the function.
+    fn make_multiplier(factor: i32) -> impl Fn(i32) -> i32 {
+        move |x| x * factor
+    }
+
+    let triple = make_multiplier(3);
+    println!("Triple 7: {}", triple(7));
+
+    // `impl Fn` only works when every return path produces the same
+    // closure type. When different closures are returned, they have to be
+    // boxed as a *trait object*, `Box<dyn Fn>`.
+    fn make_operation(name: &str) -> Box<dyn Fn(i32, i32) -> i32> {
+        match name {
+            "add" => Box::new(|a, b| a + b),
+            "max" => Box::new(|a, b| if a > b { a } else { b }),
+            _ => Box::new(|_, _| 0),
+        }
+    }
+
+    for name in ["add", "max", "unknown"] {
+        let operation = make_operation(name);
+        println!("{}(4, 9) = {}", name, operation(4, 9));
+    }
+
+    // ### Storing Closures
+
+    // Boxed closures can be stored in collections. A *callback registry*
+    // keeps a list of closures and calls all of them when an event occurs.
+    // A type alias keeps the boxed closure type readable.
+    type Listener = Box<dyn Fn(&str)>;
+
+    struct EventBus {
+        listeners: Vec<Listener>,
+    }
+
+    impl EventBus {
+        fn new() -> EventBus {
+            EventBus {
+                listeners: Vec::new(),
+            }
+        }
+
+        fn subscribe<F>(&mut self, listener: F)
+        where
+            F: Fn(&str) + 'static,
+        {
+            self.listeners.push(Box::new(listener));
+        }
+
+        fn emit(&self, event: &str) {
+            for listener in &self.listeners {
+                listener(event);
+            }
+        }
+    }
+
+    let mut bus = EventBus::new();
+    let prefix = String::from("[log]");
+    bus.subscribe(move |event| println!("{} {}", prefix, event));
+    bus.subscribe(|event| println!("Event has {} characters", event.len()));
+    bus.emit("user signed in");
+
+    // ### Memoization
+
+    // A closure can carry state between calls, like a cache of results it
+    // has already computed. Here the `HashMap` is captured by the closure,
+    // which only runs the slow computation for inputs it hasn't seen
+    // before.
+    use std::collections::HashMap;
+
+    fn slow_square(n: u64) -> u64 {
+        println!("  computing {}^2", n);
+        n * n
+    }
+
+    let mut cache: HashMap<u64, u64> = HashMap::new();
+    let mut memo_square = |n: u64| *cache.entry(n).or_insert_with(|| slow_square(n));
+
+    for n in [4, 5, 4, 4] {
+        println!("{}^2 = {}", n, memo_square(n));
+    }
 }
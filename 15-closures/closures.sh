@@ -6,3 +6,21 @@ Adding two numbers: 5
 Adding two numbers: 7
 Capturing x: 3
 Answer: 3
+Counter after three calls: 3
+count = 3
+Visits: [1, 2, 3]
+Took the name Ferris
+Hello, world
+The thread received: hello from the main thread
+Triple 7: 21
+add(4, 9) = 13
+max(4, 9) = 9
+unknown(4, 9) = 0
+[log] user signed in
+Event has 14 characters
+  computing 4^2
+4^2 = 16
+  computing 5^2
+5^2 = 25
+4^2 = 16
+4^2 = 16
@@ -7,6 +7,8 @@
 // on the heap and is therefore able to store an amount of text that is unknown
 // to us at compile time.
 
+use std::collections::HashMap;
+
 fn main() {
 
     // String slice is an immutable reference to a UTF-8 encoded string in memory
@@ -40,4 +42,150 @@ fn main() {
     // Create `String` type with `to_string()`
     let str_to_str: String = "hello, world!".to_string();
     println!("{}", str_to_str);
+
+    // ### UTF-8
+
+    // Strings are UTF-8, where a character takes between one and four bytes.
+    // `len()` returns the length in bytes, not in characters.
+    let name = "Zoë ❤";
+    println!("{:?} has {} bytes", name, name.len());
+
+    // `chars()` iterates over the characters (Unicode scalar values) and
+    // `bytes()` over the raw bytes.
+    println!("{:?} has {} chars", name, name.chars().count());
+    let chars: Vec<char> = name.chars().collect();
+    println!("chars: {:?}", chars);
+    let bytes: Vec<u8> = "ë".bytes().collect();
+    println!("bytes of \"ë\": {:?}", bytes);
+
+    // `char_indices()` yields each character together with the byte
+    // offset where it starts. The offsets skip ahead after multi-byte
+    // characters.
+    for (index, c) in name.char_indices() {
+        print!("{}:{} ", index, c);
+    }
+    println!();
+
+    // ### Slicing Safely
+
+    // Slicing uses byte offsets, and panics if an offset falls in the
+    // middle of a character. `&s[0..5]` above works because `"hello"` is
+    // ASCII, where each character is a single byte, but `&name[0..3]` would
+    // panic: byte 3 is inside the two-byte `ë`. `is_char_boundary` tells
+    // whether an offset is safe to slice at.
+    println!("Is 3 a char boundary? {}", name.is_char_boundary(3));
+    println!("Is 4 a char boundary? {}", name.is_char_boundary(4));
+
+    // `get` is the non-panicking version of slicing. It returns `None` for
+    // offsets that are out of bounds or not on a char boundary.
+    println!("name.get(0..3) = {:?}", name.get(0..3));
+    println!("name.get(0..4) = {:?}", name.get(0..4));
+
+    // To cut a string down to at most `max` bytes, we move the end back to
+    // the nearest char boundary.
+    fn truncate(text: &str, max: usize) -> &str {
+        if max >= text.len() {
+            return text;
+        }
+        let mut end = max;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        &text[..end]
+    }
+    println!("Truncated to 3 bytes: {:?}", truncate(name, 3));
+
+    // ### Splitting
+
+    // `split` divides a string at each occurrence of a separator, and
+    // returns an iterator over the parts. The separator can be a `char`, a
+    // string, or a closure that decides for each character.
+    let csv = "apple,banana,,cherry";
+    let fields: Vec<&str> = csv.split(',').collect();
+    println!("split: {:?}", fields);
+
+    // `split_whitespace` splits at any amount of whitespace, and never
+    // yields empty parts.
+    let sentence = "  the quick\tbrown   fox ";
+    let words: Vec<&str> = sentence.split_whitespace().collect();
+    println!("split_whitespace: {:?}", words);
+
+    // `lines` splits at line endings, both `\n` and `\r\n`.
+    let text = "first line\nsecond line\r\nthird line";
+    for (number, line) in text.lines().enumerate() {
+        println!("line {}: {}", number + 1, line);
+    }
+
+    // ### Searching and Replacing
+
+    // `find` returns the byte offset of the first match, if there is one.
+    let haystack = "the cat sat on the mat";
+    println!("find(\"at\") = {:?}", haystack.find("at"));
+    println!("rfind(\"at\") = {:?}", haystack.rfind("at"));
+    println!("find(\"dog\") = {:?}", haystack.find("dog"));
+    println!("contains(\"sat\") = {}", haystack.contains("sat"));
+    println!("starts_with(\"the\") = {}", haystack.starts_with("the"));
+
+    // `replace` returns a new `String` with every match replaced.
+    println!("{}", haystack.replace("at", "og"));
+
+    // `trim` removes whitespace from both ends, `trim_start` and `trim_end`
+    // from one end only. `trim_matches` removes the given characters
+    // instead. They all return slices of the original string.
+    let padded = "   padded   ";
+    println!(
+        "[{}] [{}] [{}]",
+        padded.trim(),
+        padded.trim_start(),
+        padded.trim_end()
+    );
+    println!("{}", "**bold**".trim_matches('*'));
+
+    // ### Changing Case
+
+    // `to_uppercase` and `to_lowercase` follow the Unicode rules, so the
+    // result can even have a different length: the German `ß` becomes
+    // `SS`.
+    let street = "Hauptstraße";
+    println!("{} -> {}", street, street.to_uppercase());
+    let school = "ÉCOLE";
+    println!("{} -> {}", school, school.to_lowercase());
+
+    // ### Parsing
+
+    // `parse` converts a string into any type that implements `FromStr`,
+    // like the numeric types. It returns a `Result`, since the text may not
+    // be a valid number. The error says what went wrong.
+    for input in ["42", "-17", " 7 ", "4x2", "", "99999999999"] {
+        match input.parse::<i32>() {
+            Ok(number) => println!("{:?} -> Ok({})", input, number),
+            Err(err) => println!("{:?} -> Err({})", input, err),
+        }
+    }
+
+    // Surrounding whitespace is not allowed, so input is often trimmed
+    // first.
+    println!("{:?}", " 7 ".trim().parse::<i32>());
+
+    // ### Counting Words
+
+    // Putting it together: split a text into words, normalize them, and
+    // count them in a `HashMap` with the `entry` pattern from the hash maps
+    // chapter.
+    let text = "The cat and the hat. The cat sat!";
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for word in text.split_whitespace() {
+        let word = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    // A `HashMap` has no order, so we sort the words by count, and
+    // alphabetically when the counts are equal.
+    let mut counts: Vec<(String, u32)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    for (word, count) in counts {
+        println!("{}: {}", word, count);
+    }
 }
@@ -7,3 +7,40 @@ hello, world!
 hello, world!
 hello, world!
 hello, world!
+"Zoë ❤" has 8 bytes
+"Zoë ❤" has 5 chars
+chars: ['Z', 'o', 'ë', ' ', '❤']
+bytes of "ë": [195, 171]
+0:Z 1:o 2:ë 4:  5:❤ 
+Is 3 a char boundary? false
+Is 4 a char boundary? true
+name.get(0..3) = None
+name.get(0..4) = Some("Zoë")
+Truncated to 3 bytes: "Zo"
+split: ["apple", "banana", "", "cherry"]
+split_whitespace: ["the", "quick", "brown", "fox"]
+line 1: first line
+line 2: second line
+line 3: third line
+find("at") = Some(5)
+rfind("at") = Some(20)
+find("dog") = None
+contains("sat") = true
+starts_with("the") = true
+the cog sog on the mog
+[padded] [padded   ] [   padded]
+bold
+Hauptstraße -> HAUPTSTRASSE
+ÉCOLE -> école
+"42" -> Ok(42)
+"-17" -> Ok(-17)
+" 7 " -> Err(invalid digit found in string)
+"4x2" -> Err(invalid digit found in string)
+"" -> Err(cannot parse integer from empty string)
+"99999999999" -> Err(number too large to fit in target type)
+Ok(7)
+the: 3
+cat: 2
+and: 1
+hat: 1
+sat: 1
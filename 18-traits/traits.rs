@@ -8,15 +8,35 @@
 // trait. This allows you to define shared behavior between types that are not
 // related by inheritance.
 
+use std::f64::consts::PI;
+use std::fmt;
+
 fn main() {
 
     // Define a trait named `Shape` with a method signature named `area`.
-    trait Shape {
+    //
+    // `Shape: fmt::Display` makes `Display` a *supertrait* of `Shape`: a type
+    // can only implement `Shape` if it also implements `Display`. In return,
+    // the methods of `Shape` can rely on `Display`, and format `self` with
+    // `{}`.
+    trait Shape: fmt::Display {
         fn area(&self) -> f64;
+
+        // A trait method can have a *default implementation*. Types that
+        // implement the trait get it for free, and can still override it.
+        fn describe(&self) -> String {
+            format!("{} with an area of {:.2}", self, self.area())
+        }
     }
 
     // Define two structs, `Circle` and `Rectangle`, that implement the `Shape`
     // trait. Each struct has its own implementation of the `area` method.
+    //
+    // Some traits of the standard library can be implemented automatically
+    // with `#[derive]`. `Debug` enables printing with `{:?}`, `Clone` adds a
+    // `clone` method that copies the value, and `PartialEq` enables
+    // comparisons with `==`. Derived implementations work field by field.
+    #[derive(Debug, Clone, PartialEq)]
     struct Circle {
         radius: f64,
     }
@@ -27,13 +47,22 @@ fn main() {
         }
     }
 
-    // Implement the `Shape` trait for the `Circle` struct.
+    // Implement the `Shape` trait for the `Circle` struct. Since `describe`
+    // has a default implementation, only `area` is required.
     impl Shape for Circle {
         fn area(&self) -> f64 {
-            3.4 * self.radius * self.radius
+            PI * self.radius * self.radius
+        }
+    }
+
+    // The supertrait has to be implemented as well.
+    impl fmt::Display for Circle {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Circle of radius {}", self.radius)
         }
     }
 
+    #[derive(Debug, Clone, PartialEq)]
     struct Rectangle {
         width: f64,
         height: f64,
@@ -45,11 +74,27 @@ fn main() {
         }
     }
 
-    // Implement the `Shape` trait for the `Rectangle` struct.
+    // Implement the `Shape` trait for the `Rectangle` struct. It overrides
+    // the default `describe` method.
     impl Shape for Rectangle {
         fn area(&self) -> f64 {
             self.width * self.height
         }
+
+        fn describe(&self) -> String {
+            let kind = if self.width == self.height {
+                "square"
+            } else {
+                "rectangle"
+            };
+            format!("A {} with an area of {:.2}", kind, self.area())
+        }
+    }
+
+    impl fmt::Display for Rectangle {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Rectangle of {} by {}", self.width, self.height)
+        }
     }
 
     // Create instances of `Circle` and `Rectangle` and call their `area` methods.
@@ -67,4 +112,152 @@ fn main() {
 
     print_area(&circle);
     print_area(&rectangle);
+
+    println!("{}", circle.describe());
+    println!("{}", rectangle.describe());
+    println!("{}", Rectangle::new(3.0, 3.0).describe());
+
+    // ### Derived Traits
+
+    // The derived traits work like hand-written ones.
+    let copy = circle.clone();
+    println!("{:?}", copy);
+    println!("Equal to the original: {}", copy == circle);
+    println!("Equal to a bigger one: {}", copy == Circle::new(6.0));
+
+    // ### Static and Dynamic Dispatch
+
+    // `&dyn Shape` uses *dynamic dispatch*: a trait object carries a pointer
+    // to a table of the methods of its type, which is looked up when a
+    // method is called. The alternative is a generic function, written with
+    // `impl Shape` here. It uses *static dispatch*: the compiler generates a
+    // separate copy of the function for each type it's called with, so the
+    // calls are direct and can be inlined.
+    fn print_description(shape: &impl Shape) {
+        println!("Static dispatch: {}", shape.describe());
+    }
+
+    print_description(&circle);
+    print_description(&rectangle);
+
+    // Static dispatch needs the type to be known at compile time. To store
+    // values of different types together, like in a `Vec`, they have to be
+    // trait objects. `Box<dyn Shape>` owns a value of any type that
+    // implements `Shape`.
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle::new(1.0)),
+        Box::new(Rectangle::new(2.0, 3.0)),
+        Box::new(Circle::new(2.0)),
+    ];
+
+    let total: f64 = shapes.iter().map(|shape| shape.area()).sum();
+    println!("Total area of {} shapes: {:.2}", shapes.len(), total);
+
+    // ### Associated Types
+
+    // A trait can declare an *associated type*, a placeholder type that each
+    // implementation chooses. Unlike a generic parameter, there is only one
+    // implementation of the trait per type, so users of the trait don't
+    // have to name the type. The `Iterator` trait's `Item` is an example.
+    trait Container {
+        type Item;
+
+        fn first(&self) -> Option<&Self::Item>;
+        fn len(&self) -> usize;
+    }
+
+    struct Palette {
+        colors: Vec<String>,
+    }
+
+    impl Container for Palette {
+        type Item = String;
+
+        fn first(&self) -> Option<&String> {
+            self.colors.first()
+        }
+
+        fn len(&self) -> usize {
+            self.colors.len()
+        }
+    }
+
+    struct Drawing {
+        shapes: Vec<Box<dyn Shape>>,
+    }
+
+    impl Container for Drawing {
+        type Item = Box<dyn Shape>;
+
+        fn first(&self) -> Option<&Box<dyn Shape>> {
+            self.shapes.first()
+        }
+
+        fn len(&self) -> usize {
+            self.shapes.len()
+        }
+    }
+
+    // A generic function can put bounds on the associated type, written as
+    // `C::Item`.
+    fn print_first<C>(container: &C)
+    where
+        C: Container,
+        C::Item: fmt::Display,
+    {
+        match container.first() {
+            Some(item) => println!("First of {}: {}", container.len(), item),
+            None => println!("Empty"),
+        }
+    }
+
+    let palette = Palette {
+        colors: vec![String::from("red"), String::from("green")],
+    };
+    print_first(&palette);
+    print_first(&Drawing { shapes });
+
+    // ### Associated Constants
+
+    // Traits can also declare *associated constants*, which each
+    // implementation sets. They belong to the type rather than to a value,
+    // so they are accessed with `Type::CONSTANT`. A trait with associated
+    // constants can't be used as a trait object, which is why `SIDES` isn't
+    // part of `Shape`.
+    trait Polygon {
+        const SIDES: u32;
+
+        // Default methods can use the constant through `Self`.
+        fn sides(&self) -> u32 {
+            Self::SIDES
+        }
+    }
+
+    impl Polygon for Rectangle {
+        const SIDES: u32 = 4;
+    }
+
+    println!("A rectangle has {} sides", Rectangle::SIDES);
+    println!("This one too: {}", rectangle.sides());
+
+    // ### Blanket Implementations
+
+    // A *blanket implementation* implements a trait for every type that
+    // satisfies some bounds. Here every type that implements `Display` gets
+    // a `shout` method, including our shapes, numbers and strings. The
+    // standard library does the same to give every `Display` type a
+    // `to_string` method.
+    trait Shout {
+        fn shout(&self) -> String;
+    }
+
+    impl<T: fmt::Display> Shout for T {
+        fn shout(&self) -> String {
+            format!("{}!", self.to_string().to_uppercase())
+        }
+    }
+
+    println!("{}", circle.shout());
+    println!("{}", 42.shout());
+    println!("{}", "hello".shout());
 }
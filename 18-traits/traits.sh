@@ -1,5 +1,21 @@
 # Language: shell
 $ rustc traits.rs
 $ ./traits
-Area: 85
+Area: 78.53981633974483
 Area: 200
+Circle of radius 5 with an area of 78.54
+A rectangle with an area of 200.00
+A square with an area of 9.00
+Circle { radius: 5.0 }
+Equal to the original: true
+Equal to a bigger one: false
+Static dispatch: Circle of radius 5 with an area of 78.54
+Static dispatch: A rectangle with an area of 200.00
+Total area of 3 shapes: 21.71
+First of 2: red
+First of 3: Circle of radius 1
+A rectangle has 4 sides
+This one too: 4
+CIRCLE OF RADIUS 5!
+42!
+HELLO!
@@ -11,7 +11,12 @@
 // are typically named using a single upper cased letter (e.g. `T`). Generics
 // can be used with functions, structs, enums, and traits.
 
-use std::fmt::Display;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::rc::Rc;
 
 fn main() {
 
@@ -36,6 +41,10 @@ fn main() {
     let first_float = first_element(&floats);
     let first_number = first_element(&numbers);
     let first_word = first_element(&words);
+    println!(
+        "First elements: {:?} {:?} {:?}",
+        first_float, first_number, first_word
+    );
 
     // We can also define generic functions with multiple type parameters.
     // The `combine` function is a generic function that takes two arguments
@@ -46,6 +55,7 @@ fn main() {
 
     let number_and_float = combine(10, 1.1);
     let word_and_number = combine("apple", 10);
+    println!("Combined: {:?} {:?}", number_and_float, word_and_number);
 
     // We can also use **trait bounds** to restrict the types that can be used with
     // a generic function. In this case, the `largest` function can only be used
@@ -64,6 +74,10 @@ fn main() {
     let largest_number = largest(&numbers);
     let largest_float = largest(&floats);
     let largest_word = largest(&words);
+    println!(
+        "Largest: {} {} {}",
+        largest_number, largest_float, largest_word
+    );
 
     // We can also use **multiple trait bounds** to restrict the types that can be used
     // with a generic function. In this case, the `max` function can only be
@@ -79,6 +93,7 @@ fn main() {
     let max_number = max(10, 20);
     let max_float = max(1.1, 2.2);
     let max_word = max("apple", "banana");
+    println!("Max: {} {} {}", max_number, max_float, max_word);
 
     // Structs can also be generic. In this case, `GenericStruct` is a
     // generic struct that takes a type `T` as a generic type parameter.
@@ -96,8 +111,14 @@ fn main() {
     }
 
     let number_struct = GenericStruct { field: 10 };
-    let float_struct = GenericStruct { field: 1.1 };
+    let float_struct = GenericStruct { field: 1.1f32 };
     let word_struct = GenericStruct { field: "apple" };
+    println!(
+        "Fields: {} {} {}",
+        number_struct.get_field(),
+        float_struct.get_field(),
+        word_struct.get_field()
+    );
 
     // To specify a constraint when defining a method on a generic struct, you can
     // use the `impl` block with the specific type. In this case, the
@@ -109,6 +130,10 @@ fn main() {
         }
     }
 
+    // `float_struct` holds an `f32`, so it has the method. Calling it on
+    // `number_struct` would not compile.
+    println!("Float field: {}", float_struct.get_float_field());
+
     // To specify constraints on the generic type `T`, you can use trait bounds.
     // In this case, the `DisplayStruct` can only be used with types that
     // implement the `Display` trait.
@@ -116,6 +141,11 @@ fn main() {
         field: T,
     }
 
+    let display_struct = DisplayStruct {
+        field: "displayable",
+    };
+    println!("DisplayStruct: {}", display_struct.field);
+
     // We can also use generics with enums. In this case, the `HTTPResp` enum
     // is a generic enum that can be either a `Success` or `Error` response.
     enum HTTPResp<T> {
@@ -176,7 +206,7 @@ fn main() {
     // type `T` that implements the `PartialOrd` trait.
     impl<T: PartialOrd> Sorter<T> for BubbleSorter {
         fn sort(&self, slice: &mut [T]) {
-            for i in 0..slice.len() {
+            for _ in 0..slice.len() {
                 for j in 0..slice.len() - 1 {
                     if slice[j] > slice[j + 1] {
                         slice.swap(j, j + 1);
@@ -189,8 +219,272 @@ fn main() {
     let mut numbers = vec![42, 1337, 28, 10];
     let sorter = SelectionSorter;
     sorter.sort(&mut numbers);
+    println!("SelectionSorter: {:?}", numbers);
 
     let mut words = vec!["cherry", "apple", "banana"];
     let sorter = BubbleSorter;
     sorter.sort(&mut words);
+    println!("BubbleSorter: {:?}", words);
+
+    struct InsertionSorter;
+
+    // Insertion sort takes the elements one by one, and moves each of them
+    // to the left until it's in order with the elements before it.
+    impl<T: PartialOrd> Sorter<T> for InsertionSorter {
+        fn sort(&self, slice: &mut [T]) {
+            for i in 1..slice.len() {
+                let mut j = i;
+                while j > 0 && slice[j - 1] > slice[j] {
+                    slice.swap(j - 1, j);
+                    j -= 1;
+                }
+            }
+        }
+    }
+
+    struct MergeSorter;
+
+    // Merge sort sorts both halves of the slice and merges them. Merging
+    // needs a copy of the sorted halves, so this implementation has an
+    // additional trait bound: `T` must also implement `Clone`. The other
+    // sorters work with any `PartialOrd` type, this one doesn't.
+    impl<T: PartialOrd + Clone> Sorter<T> for MergeSorter {
+        fn sort(&self, slice: &mut [T]) {
+            if slice.len() <= 1 {
+                return;
+            }
+            let middle = slice.len() / 2;
+            self.sort(&mut slice[..middle]);
+            self.sort(&mut slice[middle..]);
+
+            let left = slice[..middle].to_vec();
+            let right = slice[middle..].to_vec();
+            let (mut i, mut j) = (0, 0);
+            for item in slice.iter_mut() {
+                if j == right.len() || (i < left.len() && left[i] <= right[j]) {
+                    *item = left[i].clone();
+                    i += 1;
+                } else {
+                    *item = right[j].clone();
+                    j += 1;
+                }
+            }
+        }
+    }
+
+    // A generic function can take any sorter, as long as it can sort the
+    // element type. The bound `S: Sorter<T>` ties the two type parameters
+    // together.
+    fn sort_and_print<S, T>(name: &str, sorter: &S, items: &[T])
+    where
+        S: Sorter<T>,
+        T: Clone + Debug,
+    {
+        let mut items = items.to_vec();
+        sorter.sort(&mut items);
+        println!("{}: {:?}", name, items);
+    }
+
+    let scores = [3.5, -1.0, 12.25, 0.0, 7.75];
+    sort_and_print("InsertionSorter", &InsertionSorter, &scores);
+    sort_and_print("MergeSorter", &MergeSorter, &scores);
+
+    // All sorters above use the order given by `PartialOrd`. To sort in a
+    // different order, `sort_with` takes a *comparator*: a closure that
+    // compares two elements and returns an `Ordering`. The closure type `F`
+    // is one more generic parameter.
+    fn sort_with<T, F>(slice: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for i in 1..slice.len() {
+            let mut j = i;
+            while j > 0 && compare(&slice[j - 1], &slice[j]) == Ordering::Greater {
+                slice.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    let mut words = vec!["fig", "banana", "kiwi", "apple", "cherry"];
+    sort_with(&mut words, |a, b| b.cmp(a));
+    println!("sort_with, descending: {:?}", words);
+    sort_with(&mut words, |a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+    println!("sort_with, by length: {:?}", words);
+
+    // ### Comparing the Sorters
+
+    // To compare the sorters, we count how many comparisons each of them
+    // makes on the same input. `Counted` wraps a value and implements
+    // `PartialOrd` by comparing the values and incrementing a shared
+    // counter. Since the sorters are generic, they work with `Counted`
+    // without any changes.
+    #[derive(Clone)]
+    struct Counted {
+        value: i32,
+        comparisons: Rc<Cell<usize>>,
+    }
+
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Counted) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Counted) -> Option<Ordering> {
+            self.comparisons.set(self.comparisons.get() + 1);
+            self.value.partial_cmp(&other.value)
+        }
+    }
+
+    fn count_comparisons<S: Sorter<Counted>>(sorter: &S, input: &[i32]) -> usize {
+        let comparisons = Rc::new(Cell::new(0));
+        let mut items: Vec<Counted> = input
+            .iter()
+            .map(|&value| Counted {
+                value,
+                comparisons: Rc::clone(&comparisons),
+            })
+            .collect();
+        sorter.sort(&mut items);
+        comparisons.get()
+    }
+
+    let input = [8, 3, 14, 1, 9, 12, 5, 2, 11, 7, 4, 13, 6, 10, 15, 0];
+    let counts = [
+        ("SelectionSorter", count_comparisons(&SelectionSorter, &input)),
+        ("BubbleSorter", count_comparisons(&BubbleSorter, &input)),
+        ("InsertionSorter", count_comparisons(&InsertionSorter, &input)),
+        ("MergeSorter", count_comparisons(&MergeSorter, &input)),
+    ];
+
+    println!("Comparisons to sort {} numbers:", input.len());
+    for (name, count) in counts {
+        println!("  {}: {}", name, count);
+    }
+
+    // ### Const Generics
+
+    // Generics can also be parameterized by constant values, written as
+    // `const N: usize`. Here the dimensions of a matrix are part of its
+    // type, so a `Matrix<2, 3>` and a `Matrix<3, 2>` are different types.
+    #[derive(Debug, Clone, Copy)]
+    struct Matrix<const R: usize, const C: usize> {
+        data: [[i32; C]; R],
+    }
+
+    impl<const R: usize, const C: usize> Matrix<R, C> {
+        fn new(data: [[i32; C]; R]) -> Self {
+            Matrix { data }
+        }
+
+        // The transpose of an `R`x`C` matrix is a `C`x`R` matrix.
+        fn transpose(&self) -> Matrix<C, R> {
+            let mut data = [[0; R]; C];
+            for (r, row) in self.data.iter().enumerate() {
+                for (c, &value) in row.iter().enumerate() {
+                    data[c][r] = value;
+                }
+            }
+            Matrix { data }
+        }
+
+        // An `R`x`C` matrix can only be multiplied with a `C`x`K` matrix,
+        // and the result is `R`x`K`. Multiplying matrices of the wrong
+        // dimensions is a compile error instead of a runtime error.
+        fn multiply<const K: usize>(&self, other: &Matrix<C, K>) -> Matrix<R, K> {
+            let mut data = [[0; K]; R];
+            for r in 0..R {
+                for k in 0..K {
+                    data[r][k] = (0..C).map(|c| self.data[r][c] * other.data[c][k]).sum();
+                }
+            }
+            Matrix { data }
+        }
+    }
+
+    impl<const R: usize, const C: usize> Display for Matrix<R, C> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let rows: Vec<String> = self.data.iter().map(|row| format!("{:?}", row)).collect();
+            write!(f, "{}x{} {}", R, C, rows.join(" "))
+        }
+    }
+
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let b = a.transpose();
+    println!("a = {}", a);
+    println!("b = {}", b);
+    println!("a * b = {}", a.multiply(&b));
+    println!("b * a = {}", b.multiply(&a));
+
+    // ### Phantom Types
+
+    // A type parameter that isn't used by any field is an error, since the
+    // compiler can't tell how the type relates to it. `PhantomData<U>` is a
+    // zero-sized field that marks the type as using `U` anyway. This lets
+    // us tag values with a type that only exists at compile time, like a
+    // unit of measure. The same trick gives typed IDs, where an `Id<User>`
+    // can't be mixed up with an `Id<Order>`.
+    #[derive(Debug, Clone, Copy)]
+    struct Meters;
+
+    #[derive(Debug, Clone, Copy)]
+    struct Feet;
+
+    trait Unit {
+        const SYMBOL: &'static str;
+    }
+
+    impl Unit for Meters {
+        const SYMBOL: &'static str = "m";
+    }
+
+    impl Unit for Feet {
+        const SYMBOL: &'static str = "ft";
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Length<U> {
+        value: f64,
+        unit: PhantomData<U>,
+    }
+
+    impl<U> Length<U> {
+        fn new(value: f64) -> Length<U> {
+            Length {
+                value,
+                unit: PhantomData,
+            }
+        }
+    }
+
+    // Lengths can only be added when they have the same unit.
+    impl<U> Add for Length<U> {
+        type Output = Length<U>;
+
+        fn add(self, other: Length<U>) -> Length<U> {
+            Length::new(self.value + other.value)
+        }
+    }
+
+    // Converting between units is explicit.
+    impl Length<Feet> {
+        fn to_meters(self) -> Length<Meters> {
+            Length::new(self.value * 0.3048)
+        }
+    }
+
+    impl<U: Unit> Display for Length<U> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{:.2} {}", self.value, U::SYMBOL)
+        }
+    }
+
+    let track: Length<Meters> = Length::new(400.0);
+    let rope: Length<Feet> = Length::new(50.0);
+
+    // `track + rope` doesn't compile: the units differ.
+    println!("Rope: {}", rope);
+    println!("Track plus rope: {}", track + rope.to_meters());
 }
@@ -1,7 +1,31 @@
 # Language: shell
 $ rustc generics.rs
 $ ./generics
+First elements: Some(1.1) Some(10) Some("apple")
+Combined: (10, 1.1) ("apple", 10)
+Largest: 30 3.3 cherry
+Max: 20 2.2 banana
+Fields: 10 1.1 apple
+Float field: 1.1
+DisplayStruct: displayable
 Success: 200
 Success: OK
 Error: 404
 Error: Not Found
+SelectionSorter: [10, 28, 42, 1337]
+BubbleSorter: ["apple", "banana", "cherry"]
+InsertionSorter: [-1.0, 0.0, 3.5, 7.75, 12.25]
+MergeSorter: [-1.0, 0.0, 3.5, 7.75, 12.25]
+sort_with, descending: ["kiwi", "fig", "cherry", "banana", "apple"]
+sort_with, by length: ["fig", "kiwi", "apple", "banana", "cherry"]
+Comparisons to sort 16 numbers:
+  SelectionSorter: 120
+  BubbleSorter: 240
+  InsertionSorter: 69
+  MergeSorter: 48
+a = 2x3 [1, 2, 3] [4, 5, 6]
+b = 3x2 [1, 4] [2, 5] [3, 6]
+a * b = 2x2 [14, 32] [32, 77]
+b * a = 3x3 [17, 22, 27] [22, 29, 36] [27, 36, 45]
+Rope: 50.00 ft
+Track plus rope: 415.24 m
@@ -6,25 +6,28 @@
 // errors. `T` is the type of the success value, and `E` is the type of the error.
 
 use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
 
 fn main() -> Result<(), Box<dyn Error>> {
 
     // ### Returning Errors
 
     // To return errors in Rust, we use the `Result<T, E>` type. Here is a simple
-    // function that performs division and returns a `Result<i32, String>`.
-    fn divide(x: i32, y: i32) -> Result<i32, String> {
+    // function that performs division and returns a `Result<i32, MathError>`.
+    // `MathError` is our own error type, which is defined in the
+    // *Custom Error Types* section at the end of this chapter.
+    fn divide(x: i32, y: i32) -> Result<i32, MathError> {
         if y == 0 {
-            return Err("Division by zero".to_string());
+            return Err(MathError::DivisionByZero);
         }
         Ok(x / y)
     }
 
     // Performs checked multiplication; returns an error when an integer
     // overflow occurs.
-    fn multiply(a: i32, b: i32) -> Result<i32, String> {
-        a.checked_mul(b)
-            .ok_or("Integer overflow on multiplication".to_string())
+    fn multiply(a: i32, b: i32) -> Result<i32, MathError> {
+        a.checked_mul(b).ok_or(MathError::Overflow)
     }
 
     // To signify the absence of a value upon success, we can use `Result<()>`.
@@ -48,7 +51,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Important to note is that we lose the specific error type information
     // when using `Box<dyn Error>`. For real-world applications, creating custom
     // error types is often a better approach as it provides more specific
-    // error information.
+    // error information. We'll see how to write one in the
+    // *Custom Error Types* section.
 
     // `Result` type alias is a kind of shorthand that can be used to
     // simplify function signatures. Instead of writing `Result<T, Box<dyn Error>>`
@@ -126,6 +130,15 @@ fn main() -> Result<(), Box<dyn Error>> {
     let final_result = divide_and_multiply(20, 4, 2)?;
     println!("Final Result: {}", final_result);
 
+    // The type alias is the same type, so `?` works just the same.
+    let alias_result = divide_and_multiply_alias(9, 3, 5)?;
+    println!("Alias Result: {}", alias_result);
+
+    // With `Result<(), E>` there is no value to use, so `?` only checks for
+    // an error. A `String` error converts into `Box<dyn Error>`, like the
+    // other errors above.
+    do_something()?;
+
     // #### Unwrapping Results
 
     // `unwrap()` extracts the contents of `Ok` variant and assigns it to the
@@ -172,5 +185,93 @@ fn main() -> Result<(), Box<dyn Error>> {
         divide(10, 0).map_err(|e| format!("Custom error: {}", e));
     println!("Mapped Error Result: {:?}", result_with_mapped_err);
 
+    // ### Custom Error Types
+
+    // A custom error type is usually an enum with one variant for each way
+    // an operation can fail. Variants can carry data, like the underlying
+    // error that caused them. Deriving `Debug` is required by the `Error`
+    // trait, and lets us print the error with `{:?}`.
+    #[derive(Debug)]
+    enum MathError {
+        DivisionByZero,
+        Overflow,
+        InvalidNumber(ParseIntError),
+    }
+
+    // `Display` provides the user-facing message of the error. This is
+    // what `{}` prints, and what `to_string()` returns.
+    impl fmt::Display for MathError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MathError::DivisionByZero => write!(f, "Division by zero"),
+                MathError::Overflow => {
+                    write!(f, "Integer overflow on multiplication")
+                }
+                MathError::InvalidNumber(_) => write!(f, "Invalid number"),
+            }
+        }
+    }
+
+    // Implementing the `std::error::Error` trait makes `MathError` a proper
+    // error, so it can be boxed into a `Box<dyn Error>` like in
+    // `divide_and_multiply`. All its methods have default implementations.
+    // We override `source()`, which returns the lower-level error that
+    // caused this one, if any.
+    impl Error for MathError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                MathError::InvalidNumber(err) => Some(err),
+                _ => None,
+            }
+        }
+    }
+
+    // The `?` operator converts the error with `From::from` before returning
+    // it. Implementing `From<ParseIntError>` therefore lets `?` turn a
+    // `ParseIntError` into a `MathError` automatically.
+    impl From<ParseIntError> for MathError {
+        fn from(err: ParseIntError) -> MathError {
+            MathError::InvalidNumber(err)
+        }
+    }
+
+    // Both `?` operators below return a `MathError`: the first one converts
+    // a `ParseIntError`, the second one passes the error of `divide` on
+    // unchanged.
+    fn parse_and_divide(x: &str, y: &str) -> Result<i32, MathError> {
+        let x: i32 = x.parse()?;
+        let y: i32 = y.parse()?;
+        let quotient = divide(x, y)?;
+        Ok(quotient)
+    }
+
+    // Callers can match on the variants to handle each failure differently,
+    // which isn't possible with a `String` error.
+    for (x, y) in [("12", "4"), ("12", "0"), ("12", "four")] {
+        match parse_and_divide(x, y) {
+            Ok(quotient) => println!("{} / {} = {}", x, y, quotient),
+            Err(MathError::DivisionByZero) => {
+                println!("{} / {}: cannot divide by zero", x, y)
+            }
+            Err(err) => println!("{} / {}: {}", x, y, err),
+        }
+    }
+
+    // Since each error can have a source, errors form a chain from the
+    // high-level error down to its root cause. To report an error in full,
+    // we print it and then follow `source()` until it returns `None`.
+    fn print_error_chain(err: &dyn Error) {
+        println!("Error: {}", err);
+        let mut source = err.source();
+        while let Some(cause) = source {
+            println!("  caused by: {}", cause);
+            source = cause.source();
+        }
+    }
+
+    if let Err(err) = parse_and_divide("12", "four") {
+        print_error_chain(&err);
+    }
+
     Ok(())
 }
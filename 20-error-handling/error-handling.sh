@@ -5,14 +5,22 @@ Success: 5
 if let success: 5
 let else success: 5
 Converted to Option: 5
-Handling error with Option: Division by zero
 Division was successful.
 Division failed.
 Final Result: 10
+Alias Result: 15
 Unwrapped Result: 5
 Value with fallback: 5
-Error occurred: Division by zero. Providing default value.
 Computed Fallback: -1
 Expected Value: 5
 Mapped Value Result: Ok(10)
 Mapped Error Result: Err("Custom error: Division by zero")
+12 / 4 = 3
+12 / 0: cannot divide by zero
+12 / four: Invalid number
+Error: Invalid number
+  caused by: invalid digit found in string
+# The errors printed with `eprintln!` go to stderr.
+# {stderr}
+Handling error with Option: Division by zero
+Error occurred: Division by zero. Providing default value.
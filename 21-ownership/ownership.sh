@@ -1,6 +1,6 @@
 # Language: shell
 $ rustc ownership.rs
-$ ./ownership-borrowing
+$ ./ownership
 'hello, world!' is only valid within this scope
 x: 5, y: 5
 z: 42, z_ref: 42
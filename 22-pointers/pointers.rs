@@ -181,6 +181,12 @@ fn main() {
         }
     }
 
+    // `counter` isn't declared `mut`, and yet it can be incremented.
+    let counter = Counter::new();
+    counter.increment();
+    counter.increment();
+    println!("Counter: {}", counter.get());
+
     // The `Mutex` type is a mutual exclusion primitive useful for protecting
     // shared data. Mutexes are a way to ensure that only one thread can access
     // shared data at a time. Each thread can lock on a shared value while
@@ -6,9 +6,14 @@ reference count of rc1: 1
 reference count of rc1: 2
 reference count of rc1: 3
 reference count of rc1: 1
+# {unordered}
+# {match} reference count of arc in thread2: ?
 reference count of arc in thread2: 3
+# {match} reference count of arc in thread1: ?
 reference count of arc in thread1: 2
+# {end}
 reference count of arc1: 1
 6
+Counter: 2
 6
 Mutex { data: 6, poisoned: false, .. }
@@ -1,7 +1,259 @@
-// Title: *Coming* Lifetimes
+// Title: Lifetimes
 // Language: Rust
+//
+// Every reference in Rust has a **lifetime**, the scope for which that
+// reference is valid. Most of the time lifetimes are inferred, just like most
+// types are inferred. When the compiler can't figure out how the lifetimes of
+// references relate to each other, we annotate them with generic lifetime
+// parameters. Lifetime annotations don't change how long any value lives, they
+// describe the relationship between the lifetimes of references so the
+// borrow checker can make sure that no reference outlives the value it
+// points to.
+
+use std::fmt::Display;
 
 fn main() {
-    // Lifetimes
-    println!("Lifetimes");
+
+    // A reference must never outlive the value it borrows. If `x` were
+    // declared in an inner scope and `r` in the outer one, `r = &x` would
+    // fail to compile with "`x` does not live long enough", because `x` is
+    // dropped at the end of the inner scope while `r` still refers to it.
+    // Declaring both in the same scope makes the reference valid.
+    let x = 5;
+    let r = &x;
+    println!("r: {}", r);
+
+    // Lifetime parameters start with an apostrophe and are by convention
+    // short and lowercase, like `'a`. The `longest` function returns one of
+    // its two arguments, so the compiler needs to know how the lifetime of
+    // the returned reference relates to the arguments. The annotation says
+    // that the returned reference is valid as long as *both* arguments are.
+    fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+        if x.len() > y.len() {
+            x
+        } else {
+            y
+        }
+    }
+
+    let string1 = String::from("long string is long");
+    let string2 = "xyz";
+    println!("The longest string is: {}", longest(string1.as_str(), string2));
+
+    // The lifetime `'a` is the shorter of the two lifetimes. Here the result
+    // is only used within the inner scope, where both strings are still
+    // valid. Using `result` after the inner scope would not compile, since
+    // `string4` is dropped at its end.
+    let string3 = String::from("abcd");
+    {
+        let string4 = String::from("efghijklmn");
+        let result = longest(string3.as_str(), string4.as_str());
+        println!("The longest string is: {}", result);
+    }
+
+    // Not every parameter needs an annotation. The result of `first` only
+    // ever refers to `x`, so `y` can have an unrelated lifetime.
+    fn first<'a>(x: &'a str, y: &str) -> &'a str {
+        println!("Ignoring: {}", y);
+        x
+    }
+    println!("First: {}", first("hello", "world"));
+
+    // ### Lifetime Elision
+
+    // Writing lifetimes for every reference would be tedious, so the compiler
+    // applies three **elision rules** to fill them in:
+    // 1. Each reference parameter gets its own lifetime parameter.
+    // 2. If there is exactly one input lifetime, it is assigned to all output
+    //    lifetimes.
+    // 3. If one of the parameters is `&self` or `&mut self`, the lifetime of
+    //    `self` is assigned to all output lifetimes.
+    //
+    // Only when the rules don't determine the output lifetimes, as with
+    // `longest`, do we need to annotate. Thanks to the second rule the two
+    // signatures below are the same.
+    fn first_word(s: &str) -> &str {
+        s.split(' ').next().unwrap_or("")
+    }
+
+    fn first_word_explicit<'a>(s: &'a str) -> &'a str {
+        s.split(' ').next().unwrap_or("")
+    }
+
+    println!("First word: {}", first_word("hello world"));
+    println!("First word: {}", first_word_explicit("hello world"));
+
+    // ### Structs Holding References
+
+    // Structs can hold references too, but then every reference in the
+    // definition needs a lifetime annotation. An instance of `Excerpt` can't
+    // outlive the text that its `part` field refers to.
+    struct Excerpt<'a> {
+        part: &'a str,
+    }
+
+    // Lifetime parameters of a struct are declared after `impl` and used
+    // after the struct's name, just like generic type parameters. Thanks to
+    // the third elision rule, `announce` needs no annotations: its result
+    // gets the lifetime of `&self`.
+    impl<'a> Excerpt<'a> {
+        fn level(&self) -> i32 {
+            3
+        }
+
+        fn announce(&self, announcement: &str) -> &str {
+            println!("Attention please: {}", announcement);
+            self.part
+        }
+
+        // To return the borrowed text itself, rather than a borrow of
+        // `self`, we name the struct's lifetime `'a` in the return type.
+        // The result then stays valid even after the `Excerpt` is gone.
+        fn part(&self) -> &'a str {
+            self.part
+        }
+    }
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().unwrap_or("");
+    let excerpt = Excerpt {
+        part: first_sentence,
+    };
+    println!("Excerpt: {}", excerpt.part);
+    println!("Level: {}", excerpt.level());
+    println!("Announced: {}", excerpt.announce("a new excerpt"));
+
+    let part = {
+        let excerpt = Excerpt { part: &novel[17..] };
+        excerpt.part()
+    };
+    println!("Part: {}", part);
+
+    // ### The Static Lifetime
+
+    // The special lifetime `'static` denotes a reference that can live for
+    // the entire duration of the program. All string literals have the
+    // `'static` lifetime, since they are stored directly in the program's
+    // binary.
+    let greeting: &'static str = "I have a static lifetime.";
+    println!("{}", greeting);
+
+    // Constants and statics are `'static` as well, so references to them
+    // can be returned from any function.
+    static LANGUAGES: [&str; 3] = ["Rust", "Go", "Python"];
+
+    fn favorite() -> &'static str {
+        LANGUAGES[0]
+    }
+    println!("Favorite language: {}", favorite());
+
+    // ### Lifetime Bounds
+
+    // Following on from generics, a type parameter can be bounded by a
+    // lifetime. `T: 'a` means that all references inside `T` must outlive
+    // `'a`. Here the `Wrapper` holds a reference to a `T`, which is only
+    // valid when `T` itself lives at least as long as that reference.
+    struct Wrapper<'a, T: 'a> {
+        value: &'a T,
+    }
+
+    impl<'a, T: Display + 'a> Wrapper<'a, T> {
+        fn show(&self) -> String {
+            format!("Wrapped: {}", self.value)
+        }
+    }
+
+    let number = 42;
+    let wrapper = Wrapper { value: &number };
+    println!("{}", wrapper.show());
+
+    // Lifetime parameters, trait bounds and type parameters can all be
+    // combined in one signature. The generic parameter `T` is printed while
+    // the lifetime `'a` ties the result to the inputs.
+    fn longest_with_announcement<'a, T>(x: &'a str, y: &'a str, announcement: T) -> &'a str
+    where
+        T: Display,
+    {
+        println!("Announcement! {}", announcement);
+        if x.len() > y.len() {
+            x
+        } else {
+            y
+        }
+    }
+
+    let result = longest_with_announcement("apple", "banana", "comparing fruit");
+    println!("The longest fruit is: {}", result);
+
+    // The bound `T: 'static` means that `T` contains no references, or only
+    // `'static` ones. Owned values like `String` satisfy it, which is why
+    // `thread::spawn` requires it of the data moved into a thread.
+    fn print_static<T: Display + 'static>(value: T) {
+        println!("Static value: {}", value);
+    }
+    print_static(String::from("an owned string"));
+    print_static("a string literal");
+
+    // ### Borrowing From the Input
+
+    // Lifetimes shine in types that hand out slices of their input instead
+    // of copying it. The `Tokenizer` below splits a source string into
+    // tokens, and every token it returns is a `&'a str` pointing straight
+    // into `source`. No new strings are allocated.
+    enum Token<'a> {
+        Number(&'a str),
+        Word(&'a str),
+        Symbol(&'a str),
+    }
+
+    struct Tokenizer<'a> {
+        source: &'a str,
+        position: usize,
+    }
+
+    impl<'a> Tokenizer<'a> {
+        fn new(source: &'a str) -> Tokenizer<'a> {
+            Tokenizer {
+                source,
+                position: 0,
+            }
+        }
+
+        // Returns the longest run of characters matching `predicate`,
+        // starting at the current position.
+        fn take_while<F>(&mut self, predicate: F) -> &'a str
+        where
+            F: Fn(char) -> bool,
+        {
+            let rest = &self.source[self.position..];
+            let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+            self.position += len;
+            &rest[..len]
+        }
+
+        fn next_token(&mut self) -> Option<Token<'a>> {
+            self.take_while(char::is_whitespace);
+            let c = self.source[self.position..].chars().next()?;
+            let token = if c.is_ascii_digit() {
+                Token::Number(self.take_while(|c| c.is_ascii_digit()))
+            } else if c.is_alphabetic() {
+                Token::Word(self.take_while(char::is_alphanumeric))
+            } else {
+                let start = self.position;
+                self.position += c.len_utf8();
+                Token::Symbol(&self.source[start..self.position])
+            };
+            Some(token)
+        }
+    }
+
+    let source = String::from("let total = 40 + 2;");
+    let mut tokenizer = Tokenizer::new(&source);
+    while let Some(token) = tokenizer.next_token() {
+        match token {
+            Token::Number(number) => println!("number: {}", number),
+            Token::Word(word) => println!("word: {}", word),
+            Token::Symbol(symbol) => println!("symbol: {}", symbol),
+        }
+    }
 }
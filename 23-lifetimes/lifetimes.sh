@@ -1,5 +1,29 @@
 # Language: shell
-# Lifetimes
 $ rustc lifetimes.rs
 $ ./lifetimes
-Lifetimes
+r: 5
+The longest string is: long string is long
+The longest string is: efghijklmn
+Ignoring: world
+First: hello
+First word: hello
+First word: hello
+Excerpt: Call me Ishmael
+Level: 3
+Attention please: a new excerpt
+Announced: Call me Ishmael
+Part: Some years ago...
+I have a static lifetime.
+Favorite language: Rust
+Wrapped: 42
+Announcement! comparing fruit
+The longest fruit is: banana
+Static value: an owned string
+Static value: a string literal
+word: let
+word: total
+symbol: =
+number: 40
+symbol: +
+number: 2
+symbol: ;
@@ -1,7 +1,205 @@
-// Title: *Coming* Concurrency
+// Title: Concurrency
 // Language: Rust
+//
+// Rust's ownership and type system rule out data races at compile time, which
+// the Rust community calls *fearless concurrency*. Two marker traits do the
+// heavy lifting: a type is `Send` when it can be moved to another thread, and
+// `Sync` when it can be shared between threads by reference. Building on the
+// `Arc`, `Mutex` and `thread::spawn` from the pointers chapter, we look at
+// channels, scoped threads, `RwLock`, `Condvar` and atomics.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
 
 fn main() {
-    // Concurrency
-    println!("Concurrency");
+
+    // ### Threads
+
+    // `thread::spawn` runs a closure on a new thread and returns a
+    // `JoinHandle`. Calling `join` waits for the thread to finish and returns
+    // the value produced by the closure. The `move` keyword moves `numbers`
+    // into the thread, because the thread may outlive the current scope.
+    let numbers = [1, 2, 3, 4, 5];
+    let handle = thread::spawn(move || numbers.iter().sum::<i32>());
+    let sum = handle.join().unwrap();
+    println!("Sum computed by thread: {}", sum);
+
+    // ### Channels
+
+    // Channels let threads communicate by sending each other messages,
+    // instead of sharing memory. `mpsc::channel` returns a transmitter and a
+    // receiver; *mpsc* stands for *multiple producer, single consumer*.
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for word in ["hi", "from", "the", "thread"] {
+            tx.send(word).unwrap();
+            thread::sleep(Duration::from_millis(10));
+        }
+    });
+
+    // The receiver can be used as an iterator, which ends once every
+    // transmitter has been dropped. Messages from a single producer arrive
+    // in the order they were sent.
+    for received in rx {
+        println!("Got: {}", received);
+    }
+
+    // To have multiple producers, we clone the transmitter for each thread.
+    // Messages from different threads interleave in an unpredictable order,
+    // so we tag each message with its producer and sort them before
+    // printing.
+    let (tx, rx) = mpsc::channel();
+    for id in 1..=3 {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for n in 0..2 {
+                tx.send((id, n * 10 + id)).unwrap();
+            }
+        });
+    }
+
+    // The original transmitter must be dropped as well, otherwise the
+    // receiver keeps waiting for messages that will never come.
+    drop(tx);
+
+    let mut messages: Vec<(i32, i32)> = rx.iter().collect();
+    messages.sort();
+    for (id, value) in messages {
+        println!("Producer {} sent {}", id, value);
+    }
+
+    // ### Scoped Threads
+
+    // `thread::scope` creates threads that are guaranteed to finish before
+    // the scope ends. Because of that, they are allowed to borrow local
+    // variables instead of requiring `move` and `'static` data. Here two
+    // threads read from `data` at the same time, while the scope itself
+    // collects their results into `results`.
+    let data = vec![1, 2, 3, 4, 5, 6];
+    let mut results = Vec::new();
+
+    thread::scope(|s| {
+        let evens = s.spawn(|| data.iter().filter(|&&n| n % 2 == 0).count());
+        let odds = s.spawn(|| data.iter().filter(|&&n| n % 2 == 1).count());
+        results.push(evens.join().unwrap());
+        results.push(odds.join().unwrap());
+    });
+
+    // All threads have been joined at this point, so `data` can be used
+    // again.
+    println!("Evens and odds in {:?}: {:?}", data, results);
+
+    // ### Shared State with `RwLock`
+
+    // A `RwLock` allows any number of readers or a single writer at a time.
+    // It fits data that is read often and written rarely, where a `Mutex`
+    // would needlessly make the readers wait for each other.
+    let config = Arc::new(RwLock::new(String::from("v1")));
+
+    let readers: Vec<_> = (0..3)
+        .map(|_| {
+            let config = Arc::clone(&config);
+            thread::spawn(move || config.read().unwrap().len())
+        })
+        .collect();
+    for reader in readers {
+        println!("Reader saw a config of length {}", reader.join().unwrap());
+    }
+
+    {
+        // Taking the write lock waits until all readers are done.
+        let mut config = config.write().unwrap();
+        config.push_str("-updated");
+    }
+    println!("Config is now: {}", config.read().unwrap());
+
+    // ### Waiting with `Condvar`
+
+    // A condition variable lets a thread sleep until another thread signals
+    // that something changed. It is always paired with a `Mutex` guarding
+    // the condition itself. `wait_while` releases the lock while sleeping
+    // and re-checks the condition when woken up, which also protects
+    // against spurious wakeups.
+    let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    let worker_pair = Arc::clone(&pair);
+
+    let worker = thread::spawn(move || {
+        let (ready, condvar) = &*worker_pair;
+        let ready = condvar.wait_while(ready.lock().unwrap(), |ready| !*ready);
+        println!("Worker started, ready is {}", ready.unwrap());
+    });
+
+    {
+        // The lock is held until the end of this block, so the worker can't
+        // start before the message below is printed.
+        let (ready, condvar) = &*pair;
+        let mut ready = ready.lock().unwrap();
+        *ready = true;
+        println!("Main thread signals the worker");
+        condvar.notify_one();
+    }
+    worker.join().unwrap();
+
+    // ### Atomics
+
+    // Atomic types such as `AtomicUsize` can be updated from several threads
+    // without a lock. Every operation takes an `Ordering` that describes how
+    // it synchronizes with other threads; for a plain counter `Relaxed` is
+    // enough.
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("Counter: {}", counter.load(Ordering::Relaxed));
+
+    // ### Parallel Word Count
+
+    // Putting it together, we split a text into chunks and count the words
+    // of each chunk on its own scoped thread. Every thread builds a local
+    // `HashMap`, and the partial counts are merged into the shared result
+    // behind a `Mutex` once a thread is done. Sorting the result keeps the
+    // output deterministic.
+    let text = "the quick brown fox jumps over the lazy dog \
+                the dog barks and the fox runs away";
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let totals = Mutex::new(HashMap::new());
+
+    thread::scope(|s| {
+        for chunk in words.chunks(5) {
+            let totals = &totals;
+            s.spawn(move || {
+                let mut counts = HashMap::new();
+                for word in chunk {
+                    *counts.entry(*word).or_insert(0) += 1;
+                }
+                let mut totals = totals.lock().unwrap();
+                for (word, count) in counts {
+                    *totals.entry(word).or_insert(0) += count;
+                }
+            });
+        }
+    });
+
+    let mut totals: Vec<(&str, i32)> = totals.into_inner().unwrap().into_iter().collect();
+    totals.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    for (word, count) in totals.iter().take(4) {
+        println!("{}: {}", word, count);
+    }
 }
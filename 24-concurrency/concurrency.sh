@@ -1,5 +1,26 @@
 # Language: shell
-# Concurrency
 $ rustc concurrency.rs
 $ ./concurrency
-Concurrency
+Sum computed by thread: 15
+Got: hi
+Got: from
+Got: the
+Got: thread
+Producer 1 sent 1
+Producer 1 sent 11
+Producer 2 sent 2
+Producer 2 sent 12
+Producer 3 sent 3
+Producer 3 sent 13
+Evens and odds in [1, 2, 3, 4, 5, 6]: [3, 3]
+Reader saw a config of length 2
+Reader saw a config of length 2
+Reader saw a config of length 2
+Config is now: v1-updated
+Main thread signals the worker
+Worker started, ready is true
+Counter: 4000
+the: 4
+dog: 2
+fox: 2
+and: 1
@@ -1,7 +1,235 @@
-// Title: *Coming* Async
+// Title: Async
 // Language: Rust
+//
+// Asynchronous code lets a program wait for many things at once, without a
+// thread for each of them. In Rust, an asynchronous computation is a value
+// implementing the `Future` trait, and `async`/`.await` are syntax for
+// writing such values. Unlike other languages, Rust doesn't ship a runtime
+// that runs futures: that job belongs to an *executor*, usually provided by a
+// crate like `tokio`. To see how the pieces fit together, we build a minimal
+// executor using nothing but the standard library.
+//
+// Since `async` is a keyword from the 2018 edition onwards, compile this
+// example with `rustc --edition 2021 async.rs`.
+
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::Duration;
 
 fn main() {
-    // Async
-    println!("Async");
+
+    // ### Futures
+
+    // A future is a value that might not be ready yet. Its `poll` method
+    // either returns `Poll::Ready` with the output, or `Poll::Pending` when
+    // the work isn't done. A future that returns `Pending` promises to call
+    // the *waker* from the `Context` once it can make progress, so the
+    // executor knows when to poll it again.
+    //
+    // The `Countdown` future needs a few polls to complete. It wakes itself
+    // right away, telling the executor to poll it again immediately.
+    struct Countdown {
+        remaining: u32,
+    }
+
+    impl Future for Countdown {
+        type Output = &'static str;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.remaining == 0 {
+                return Poll::Ready("liftoff!");
+            }
+            println!("countdown: {}", self.remaining);
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    // ### A Minimal Executor
+
+    // A `Waker` can be created from any type implementing the `Wake` trait.
+    // Ours holds a handle to the thread running the executor, and waking it
+    // unparks that thread.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    // `block_on` runs a single future to completion on the current thread.
+    // The future is pinned first: futures may hold references into
+    // themselves, so they must not move once they have been polled. Between
+    // polls the thread parks, sleeping until a waker unparks it.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    println!("{}", block_on(Countdown { remaining: 3 }));
+
+    // ### `async` and `.await`
+
+    // Writing `poll` by hand is tedious. An `async fn` returns a future
+    // whose body runs when it's polled, and inside it `.await` waits for
+    // another future to complete. The compiler turns the function into a
+    // state machine implementing `Future` for us.
+    async fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    async fn compute() -> i32 {
+        let x = add(1, 2).await;
+        let y = add(x, 10).await;
+        y * 2
+    }
+
+    println!("computed: {}", block_on(compute()));
+
+    // Futures are lazy: creating one doesn't run any of its code. An
+    // `async` block creates a future inline, and nothing is printed until
+    // it is handed to the executor.
+    let lazy = async {
+        println!("the async block is running");
+    };
+    println!("created the async block");
+    block_on(lazy);
+
+    // Futures can await other futures, including hand-written ones.
+    let launch = async {
+        let message = Countdown { remaining: 2 }.await;
+        format!("the countdown said {}", message)
+    };
+    println!("{}", block_on(launch));
+
+    // ### A Timer Future
+
+    // Real futures wait for something outside the program, like a socket or
+    // a timer. The `Timer` future completes after a duration has passed. It
+    // starts a thread that sleeps and then calls the waker stored by the
+    // last `poll`. The state is shared between the future and the thread,
+    // so it lives behind an `Arc<Mutex<..>>`.
+    struct TimerState {
+        completed: bool,
+        waker: Option<Waker>,
+    }
+
+    struct Timer {
+        state: Arc<Mutex<TimerState>>,
+    }
+
+    impl Timer {
+        fn new(duration: Duration) -> Timer {
+            let state = Arc::new(Mutex::new(TimerState {
+                completed: false,
+                waker: None,
+            }));
+
+            let thread_state = Arc::clone(&state);
+            thread::spawn(move || {
+                thread::sleep(duration);
+                let mut state = thread_state.lock().unwrap();
+                state.completed = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+
+            Timer { state }
+        }
+    }
+
+    impl Future for Timer {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut state = self.state.lock().unwrap();
+            if state.completed {
+                Poll::Ready(())
+            } else {
+                // The future may have moved to another task since the last
+                // poll, so the most recent waker is always stored.
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    async fn delayed(name: &str, millis: u64) -> &str {
+        Timer::new(Duration::from_millis(millis)).await;
+        println!("{} finished after {}ms", name, millis);
+        name
+    }
+
+    block_on(delayed("timer", 20));
+
+    // ### Joining Futures
+
+    // Awaiting futures one after the other runs them in sequence. To run
+    // them concurrently, we poll all of them each time we are woken up until
+    // every one of them is done. `JoinAll` keeps the output of each future
+    // that finished, and is ready once all outputs are there. The futures
+    // are boxed, which pins them on the heap.
+    struct JoinAll<F: Future> {
+        futures: Vec<Pin<Box<F>>>,
+        outputs: Vec<Option<F::Output>>,
+    }
+
+    fn join_all<F: Future>(futures: Vec<F>) -> JoinAll<F> {
+        let outputs = futures.iter().map(|_| None).collect();
+        let futures = futures.into_iter().map(Box::pin).collect();
+        JoinAll { futures, outputs }
+    }
+
+    // The futures are pinned in their boxes and the outputs are never
+    // pinned, so `JoinAll` itself can be moved freely. Implementing `Unpin`
+    // lets `poll` get a plain `&mut` to it.
+    impl<F: Future> Unpin for JoinAll<F> {}
+
+    impl<F: Future> Future for JoinAll<F> {
+        type Output = Vec<F::Output>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = &mut *self;
+            let mut done = true;
+            for (future, output) in this.futures.iter_mut().zip(&mut this.outputs) {
+                if output.is_none() {
+                    match future.as_mut().poll(cx) {
+                        Poll::Ready(value) => *output = Some(value),
+                        Poll::Pending => done = false,
+                    }
+                }
+            }
+
+            if done {
+                Poll::Ready(this.outputs.iter_mut().filter_map(Option::take).collect())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    // The three timers run at the same time, so they usually finish in order
+    // of their duration, and the whole join takes about as long as the
+    // slowest one instead of the sum of all three. The outputs keep the
+    // order in which the futures were passed in, however they finished.
+    let names = block_on(join_all(vec![
+        delayed("slow", 150),
+        delayed("fast", 50),
+        delayed("medium", 100),
+    ]));
+    println!("joined: {:?}", names);
 }
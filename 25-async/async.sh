@@ -1,5 +1,21 @@
 # Language: shell
-# Async
-$ rustc async.rs
+$ rustc --edition 2021 async.rs
 $ ./async
-Async
+countdown: 3
+countdown: 2
+countdown: 1
+liftoff!
+computed: 26
+created the async block
+the async block is running
+countdown: 2
+countdown: 1
+the countdown said liftoff!
+timer finished after 20ms
+# On a busy machine the timers may finish in a different order.
+# {unordered}
+fast finished after 50ms
+medium finished after 100ms
+slow finished after 150ms
+# {end}
+joined: ["slow", "fast", "medium"]
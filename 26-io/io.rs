@@ -1,7 +1,137 @@
-// Title: *Coming* I/O
+// Title: I/O
 // Language: Rust
+//
+// The standard library provides file system access in `std::fs` and the
+// traits for reading and writing data in `std::io`. Most I/O operations can
+// fail, so they return an `io::Result<T>`, which is a `Result<T, io::Error>`.
+// Here we write, read and append to files, use buffered readers and writers,
+// walk a directory tree, and end with a line filter that reads from stdin.
 
-fn main() {
-    // I/O
-    println!("I/O");
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+// Returning an `io::Result` from `main` lets us use the `?` operator on all
+// I/O operations. When an error is returned, it is printed and the program
+// exits with a non-zero status.
+fn main() -> io::Result<()> {
+
+    // ### Scratch Files
+
+    // `env::temp_dir` returns the directory for temporary files of the
+    // platform, like `/tmp` on Linux. We create a scratch directory in it,
+    // named after the process id so runs don't interfere with each other.
+    let scratch = env::temp_dir().join(format!("rust-by-example-io-{}", process::id()));
+    fs::create_dir_all(&scratch)?;
+
+    // ### Reading and Writing Files
+
+    // `fs::write` creates a file, or truncates it when it already exists,
+    // and writes the given contents to it in one go.
+    let path = scratch.join("greeting.txt");
+    fs::write(&path, "hello\nworld\n")?;
+
+    // `fs::read_to_string` reads a whole file into a `String`. The contents
+    // must be valid UTF-8; use `fs::read` to get the raw bytes instead.
+    let contents = fs::read_to_string(&path)?;
+    print!("{}", contents);
+
+    let bytes = fs::read(&path)?;
+    println!("greeting.txt is {} bytes", bytes.len());
+
+    // To append to a file, we open it with `OpenOptions`. The `writeln!`
+    // macro works on anything implementing the `Write` trait, including
+    // files.
+    let mut file = OpenOptions::new().append(true).open(&path)?;
+    writeln!(file, "again")?;
+    println!("after appending: {:?}", fs::read_to_string(&path)?);
+
+    // Opening a file that doesn't exist fails with an `io::Error`. Its
+    // `kind` tells what went wrong, so we can handle specific errors.
+    match File::open(scratch.join("missing.txt")) {
+        Ok(_) => println!("missing.txt exists"),
+        Err(err) => println!("could not open missing.txt: {}", err.kind()),
+    }
+
+    // ### Buffered Writing
+
+    // Every `write` on a `File` is a system call. A `BufWriter` collects
+    // small writes in memory and writes them to the file in larger chunks.
+    // The buffer is flushed when the writer is dropped, but calling `flush`
+    // explicitly lets us handle any errors.
+    let lines_path = scratch.join("lines.txt");
+    let mut writer = BufWriter::new(File::create(&lines_path)?);
+    for i in 1..=3 {
+        writeln!(writer, "line {}", i)?;
+    }
+    writer.flush()?;
+
+    // ### Buffered Reading
+
+    // Likewise, a `BufReader` reads a file in larger chunks. It implements
+    // the `BufRead` trait, whose `lines` method returns an iterator over the
+    // lines, without their line endings. Each line is an `io::Result`,
+    // since reading may fail halfway through the file.
+    let reader = BufReader::new(File::open(&lines_path)?);
+    for (number, line) in reader.lines().enumerate() {
+        println!("{}: {}", number + 1, line?);
+    }
+
+    // ### Directories
+
+    // `fs::create_dir_all` creates a directory and all of its missing
+    // parents, like `mkdir -p`.
+    let tree = scratch.join("tree");
+    fs::create_dir_all(tree.join("nested/deeper"))?;
+    fs::write(tree.join("a.txt"), "a")?;
+    fs::write(tree.join("nested/b.txt"), "b")?;
+    fs::write(tree.join("nested/deeper/c.txt"), "c")?;
+
+    // `fs::read_dir` lists the entries of a single directory. To walk the
+    // whole tree, we call it recursively for each subdirectory.
+    fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    // The order of directory entries depends on the platform, so we sort
+    // the paths before printing them relative to the tree.
+    let mut files = Vec::new();
+    walk(&tree, &mut files)?;
+    files.sort();
+    for file in &files {
+        let relative = file.strip_prefix(&tree).unwrap_or(file);
+        println!("found {}", relative.display());
+    }
+
+    // `fs::remove_dir_all` deletes the scratch directory with everything
+    // in it.
+    fs::remove_dir_all(&scratch)?;
+
+    // ### Line Filters
+
+    // A *line filter* reads input on stdin, processes it and prints the
+    // result on stdout, like `grep` and `sed` do. This one writes its input
+    // in uppercase. Locking stdin and stdout once, instead of on every line,
+    // avoids the overhead of taking the lock repeatedly. When stdin is a
+    // terminal rather than a pipe there is no input to filter, so we stop.
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Ok(());
+    }
+
+    let mut stdout = io::stdout().lock();
+    for line in stdin.lock().lines() {
+        writeln!(stdout, "{}", line?.to_uppercase())?;
+    }
+    Ok(())
 }
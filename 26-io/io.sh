@@ -1,5 +1,22 @@
 # Language: shell
-# I/O
 $ rustc io.rs
+# The line filter at the end of the program reads its input from stdin,
+# which is given here with a heredoc, and writes it in uppercase.
 $ ./io
-I/O
+# {stdin}
+hello
+line filter
+# {end}
+hello
+world
+greeting.txt is 12 bytes
+after appending: "hello\nworld\nagain\n"
+could not open missing.txt: entity not found
+1: line 1
+2: line 2
+3: line 3
+found a.txt
+found nested/b.txt
+found nested/deeper/c.txt
+HELLO
+LINE FILTER
@@ -1,7 +1,250 @@
-// Title: *Coming* Macros
+// Title: Macros
 // Language: Rust
+//
+// Macros are a way of writing code that writes other code, which is known as
+// *metaprogramming*. We have been using macros all along: `println!`, `vec!`
+// and `format!` are macros, recognizable by the `!` after their name. Unlike
+// functions, macros take a variable number of arguments and are expanded at
+// compile time, before the code is type checked. Here we look at
+// *declarative macros*, defined with `macro_rules!`, which match their input
+// against patterns and replace it with code.
+//
+// Since the 2021 edition, a `pat` fragment also matches or-patterns like
+// `'a' | 'e'`, which `matches_any!` below relies on. Earlier editions stop at
+// the `|`, so compile this example with `rustc --edition 2021 macros.rs`.
+
+use std::collections::HashMap;
 
 fn main() {
-    // Macros
-    println!("Macros");
+
+    // ### A First Macro
+
+    // A macro is defined with `macro_rules!` followed by its name and a set
+    // of *rules*. Each rule has a pattern in parentheses, `=>`, and the code
+    // it expands to in braces. This one takes no arguments, so its pattern
+    // is empty. Macros must be defined before they are used.
+    macro_rules! say_hello {
+        () => {
+            println!("Hello!");
+        };
+    }
+
+    say_hello!();
+
+    // ### Fragment Specifiers
+
+    // Arguments are matched by *metavariables*, written as `$name:kind`.
+    // The fragment specifier after the colon says what kind of syntax the
+    // metavariable matches:
+    // 1. `expr` an expression, like `1 + 2` or `foo()`
+    // 2. `ident` an identifier, like `x` or `my_function`
+    // 3. `ty` a type, like `i32` or `Vec<String>`
+    // 4. `literal` a literal, like `42` or `"text"`
+    // 5. `pat` a pattern, like `Some(_)` or `1..=5`
+    // 6. `block` a block of code in braces
+    // 7. `tt` a single *token tree*: one token, or tokens inside brackets
+    //
+    // `stringify!` turns its input into a string literal without evaluating
+    // it, which makes for a handy debugging macro.
+    macro_rules! print_expr {
+        ($e:expr) => {
+            println!("{} = {:?}", stringify!($e), $e);
+        };
+    }
+
+    print_expr!(1 + 2 * 3);
+    print_expr!("hello".len());
+
+    // An `ident` can be used to name new items, like a function.
+    macro_rules! create_function {
+        ($name:ident) => {
+            fn $name() {
+                println!("You called {}()", stringify!($name));
+            }
+        };
+    }
+
+    create_function!(foo);
+    create_function!(bar);
+    foo();
+    bar();
+
+    // A `ty` can be used anywhere a type is expected. Here it defines a
+    // struct with a field of the given type and a `block` as the body of
+    // its method.
+    macro_rules! make_wrapper {
+        ($name:ident, $t:ty, $body:block) => {
+            struct $name {
+                value: $t,
+            }
+
+            impl $name {
+                fn describe(&self) -> String $body
+            }
+        };
+    }
+
+    make_wrapper!(Meters, f64, { String::from("a distance in meters") });
+    let distance = Meters { value: 3.5 };
+    println!("{} is {}", distance.value, distance.describe());
+
+    // A `pat` can be used in a `match`. The `matches_any!` macro checks if
+    // a value matches a pattern, like the `matches!` macro from the
+    // standard library does. The pattern may be an or-pattern.
+    macro_rules! matches_any {
+        ($value:expr, $pattern:pat) => {
+            match $value {
+                $pattern => true,
+                _ => false,
+            }
+        };
+    }
+
+    println!("7 is a digit: {}", matches_any!(7, 0..=9));
+    println!("'x' is a vowel: {}", matches_any!('x', 'a' | 'e' | 'i' | 'o' | 'u'));
+
+    // ### Multiple Rules
+
+    // A macro can have several rules, which are tried from top to bottom.
+    // The first rule whose pattern matches the input is expanded. Literal
+    // tokens in a pattern, like `square` below, must appear in the input
+    // exactly.
+    macro_rules! area {
+        (square $side:expr) => {
+            $side * $side
+        };
+        (rectangle $width:expr, $height:expr) => {
+            $width * $height
+        };
+    }
+
+    println!("square: {}", area!(square 4));
+    println!("rectangle: {}", area!(rectangle 3, 5));
+
+    // ### Repetition
+
+    // Patterns can repeat with `$(...),*`, which matches zero or more
+    // repetitions separated by commas. `+` instead of `*` requires at least
+    // one repetition, and `?` makes the pattern optional. The same
+    // `$(...)*` syntax in the expansion repeats the code for every match.
+    macro_rules! sum {
+        ($($x:expr),*) => {
+            0 $(+ $x)*
+        };
+    }
+
+    println!("sum: {}", sum!());
+    println!("sum: {}", sum!(1, 2, 3, 4));
+
+    // This is how `vec!` works. Our `my_vec!` creates a vector and pushes
+    // every element. The trailing `$(,)?` accepts an optional trailing
+    // comma.
+    macro_rules! my_vec {
+        ($($x:expr),* $(,)?) => {{
+            let mut v = Vec::new();
+            $(v.push($x);)*
+            v
+        }};
+    }
+
+    println!("my_vec: {:?}", my_vec![1, 2, 3,]);
+
+    // Building on `HashMap::from` from the hash maps chapter, a `hashmap!`
+    // macro gives us map literals. Each repetition matches a `key => value`
+    // pair. The double braces make the expansion a block expression, so
+    // the macro can be used where a value is expected.
+    macro_rules! hashmap {
+        ($($key:expr => $value:expr),* $(,)?) => {{
+            let mut map = HashMap::new();
+            $(map.insert($key, $value);)*
+            map
+        }};
+    }
+
+    let ages = hashmap! {
+        "Alice" => 42,
+        "Bob" => 24,
+        "Charlie" => 36,
+    };
+
+    // The iteration order of a `HashMap` is unspecified, so we sort the
+    // entries before printing them.
+    let mut entries: Vec<_> = ages.iter().collect();
+    entries.sort();
+    println!("hashmap: {:?}", entries);
+
+    // ### Recursion
+
+    // Macros can call themselves. `max!` compares the first value with the
+    // maximum of the rest, until a single value is left.
+    //
+    // Each use of `$x` in the expansion pastes in the whole expression, so
+    // writing `if $x > rest { $x }` would evaluate it twice, running any
+    // side effects twice too. Binding it to a variable first evaluates it
+    // once, like a function argument.
+    macro_rules! max {
+        ($x:expr) => {
+            $x
+        };
+        ($x:expr, $($rest:expr),+) => {{
+            let x = $x;
+            let rest = max!($($rest),+);
+            if x > rest { x } else { rest }
+        }};
+    }
+
+    println!("max: {}", max!(3, 9, 4, 1));
+
+    // A *TT muncher* is a recursive macro that processes its input one
+    // piece at a time. Each step matches the first few tokens, and passes
+    // the remaining tokens, captured as `$($rest:tt)*`, to the next call.
+    // `calc!` evaluates words like `plus` and `times` from left to right,
+    // carrying the result so far in an internal `@acc` rule.
+    macro_rules! calc {
+        (@acc $acc:expr;) => {
+            $acc
+        };
+        (@acc $acc:expr; plus $n:literal $($rest:tt)*) => {
+            calc!(@acc ($acc + $n); $($rest)*)
+        };
+        (@acc $acc:expr; minus $n:literal $($rest:tt)*) => {
+            calc!(@acc ($acc - $n); $($rest)*)
+        };
+        (@acc $acc:expr; times $n:literal $($rest:tt)*) => {
+            calc!(@acc ($acc * $n); $($rest)*)
+        };
+        ($n:literal $($rest:tt)*) => {
+            calc!(@acc $n; $($rest)*)
+        };
+    }
+
+    println!("calc: {}", calc!(1 plus 2 times 3));
+    println!("calc: {}", calc!(10 minus 4 times 2 plus 1));
+
+    // ### Hygiene
+
+    // Macros in Rust are *hygienic*: identifiers introduced by a macro live
+    // in their own context and can't clash with identifiers at the call
+    // site. The `a` defined inside `double_it!` is a different variable
+    // from the `a` in the expression passed to it.
+    macro_rules! double_it {
+        ($e:expr) => {{
+            let a = 2;
+            $e * a
+        }};
+    }
+
+    let a = 10;
+    println!("double_it: {}", double_it!(a + 1));
+
+    // To let a macro define a variable that the caller can use, the caller
+    // passes in the identifier. The name then comes from the call site.
+    macro_rules! make_var {
+        ($name:ident, $value:expr) => {
+            let $name = $value;
+        };
+    }
+
+    make_var!(answer, 42);
+    println!("answer: {}", answer);
 }
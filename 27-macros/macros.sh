@@ -1,4 +1,22 @@
 # Language: shell
-# Macros
-$ rustc macros.rs
+$ rustc --edition 2021 macros.rs
 $ ./macros
+Hello!
+1 + 2 * 3 = 7
+"hello".len() = 5
+You called foo()
+You called bar()
+3.5 is a distance in meters
+7 is a digit: true
+'x' is a vowel: false
+square: 16
+rectangle: 15
+sum: 0
+sum: 10
+my_vec: [1, 2, 3]
+hashmap: [("Alice", 42), ("Bob", 24), ("Charlie", 36)]
+max: 9
+calc: 9
+calc: 13
+double_it: 22
+answer: 42
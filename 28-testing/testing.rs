@@ -1,7 +1,162 @@
-// Title: *Coming* Testing
+// Title: Testing
 // Language: Rust
+//
+// Rust has a test framework built in. A test is a function annotated with
+// `#[test]`, and compiling with `rustc --test` (or running `cargo test`)
+// produces a binary that runs all tests and reports the results. A test
+// passes when it returns without panicking. Unit tests are typically placed
+// in the same file as the code they test, in a module named `tests`.
 
+// This is the code we want to test: a few small functions and a struct.
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// Following on from the error handling chapter, `divide` returns an error
+// instead of panicking when dividing by zero.
+fn divide(x: i32, y: i32) -> Result<i32, String> {
+    if y == 0 {
+        return Err("Division by zero".to_string());
+    }
+    Ok(x / y)
+}
+
+// `Stack::pop` panics when the stack is empty, which is something we want
+// to test as well.
+struct Stack {
+    items: Vec<i32>,
+}
+
+impl Stack {
+    fn new() -> Stack {
+        Stack { items: Vec::new() }
+    }
+
+    fn push(&mut self, item: i32) {
+        self.items.push(item);
+    }
+
+    fn pop(&mut self) -> i32 {
+        self.items.pop().expect("pop on an empty stack")
+    }
+}
+
+/// Returns "Fizz" for multiples of three, "Buzz" for multiples of five,
+/// "FizzBuzz" for multiples of both, and the number itself otherwise.
+///
+/// Documentation comments like this one can contain examples. In a library
+/// crate, `cargo test` compiles and runs each example as a *doc test*, which
+/// keeps the examples in the documentation correct. A doc test is compiled
+/// like code that uses the library from outside, so it can only call `pub`
+/// functions, and imports them first. Lines starting with `#` are compiled,
+/// but hidden from the docs:
+///
+/// ```
+/// # use testing::fizzbuzz;
+/// assert_eq!(fizzbuzz(15), "FizzBuzz");
+/// ```
+///
+/// Doc tests only run for libraries. This file is a program, so the example
+/// above is never run, and `rustc --test` doesn't run doc tests either.
+fn fizzbuzz(n: u32) -> String {
+    match (n % 3, n % 5) {
+        (0, 0) => "FizzBuzz".to_string(),
+        (0, _) => "Fizz".to_string(),
+        (_, 0) => "Buzz".to_string(),
+        _ => n.to_string(),
+    }
+}
+
+// When compiled without `--test`, the program runs `main` as usual.
 fn main() {
-    // Testing
-    println!("Testing");
+    println!("add(2, 3) = {}", add(2, 3));
+    println!("divide(10, 2) = {:?}", divide(10, 2));
+
+    let mut stack = Stack::new();
+    stack.push(1);
+    println!("popped {}", stack.pop());
+
+    let fizzbuzz: Vec<String> = (1..=5).map(fizzbuzz).collect();
+    println!("fizzbuzz: {}", fizzbuzz.join(" "));
+}
+
+// The `#[cfg(test)]` attribute only compiles the `tests` module when
+// building tests, so it adds nothing to the normal program. Because
+// `tests` is a child module, `use super::*` brings all items of the
+// parent module into scope, including private ones.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `assert_eq!` compares two values and panics when they differ. The
+    // panic message shows both values, which makes failures easy to read.
+    #[test]
+    fn adds_two_numbers() {
+        assert_eq!(add(2, 3), 5);
+    }
+
+    // `assert_ne!` checks that two values are different. Both macros, and
+    // the plain `assert!`, accept an optional message with format
+    // arguments.
+    #[test]
+    fn adding_a_negative_number_changes_the_result() {
+        let result = add(2, -3);
+        assert_ne!(result, 2, "adding -3 should change {}", 2);
+        assert!(result < 0);
+    }
+
+    // `#[should_panic]` makes a test pass only if it panics. The
+    // `expected` argument additionally checks that the panic message
+    // contains the given text, so the test doesn't pass for the wrong
+    // reason.
+    #[test]
+    #[should_panic(expected = "empty stack")]
+    fn popping_an_empty_stack_panics() {
+        let mut stack = Stack::new();
+        stack.pop();
+    }
+
+    // Tests can also return a `Result`. The test fails when it returns an
+    // `Err`, which lets us use the `?` operator instead of unwrapping.
+    #[test]
+    fn divides_two_numbers() -> Result<(), String> {
+        let quotient = divide(10, 2)?;
+        assert_eq!(quotient, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn dividing_by_zero_is_an_error() {
+        assert_eq!(divide(1, 0), Err("Division by zero".to_string()));
+    }
+
+    // *Table-driven tests* check many inputs in one test. Each case is a
+    // row of input and expected output, and the loop checks them all. The
+    // assertion message tells which case failed.
+    #[test]
+    fn fizzbuzz_table() {
+        let cases = [
+            (1, "1"),
+            (3, "Fizz"),
+            (5, "Buzz"),
+            (9, "Fizz"),
+            (10, "Buzz"),
+            (15, "FizzBuzz"),
+            (16, "16"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(fizzbuzz(input), expected, "fizzbuzz({})", input);
+        }
+    }
+
+    // Tests marked with `#[ignore]` are skipped unless the test binary is
+    // run with `--ignored`. This is useful for tests that are slow or need
+    // special setup. The optional reason is shown in the test output.
+    #[test]
+    #[ignore = "slow"]
+    fn fizzbuzz_many_numbers() {
+        let buzzes = (1..=100_000).filter(|&n| fizzbuzz(n) == "Buzz").count();
+        assert_eq!(buzzes, 13_334);
+    }
 }
@@ -1,4 +1,34 @@
 # Language: shell
-# Testing
 $ rustc testing.rs
 $ ./testing
+add(2, 3) = 5
+divide(10, 2) = Ok(5)
+popped 1
+fizzbuzz: 1 2 Fizz 4 Buzz
+
+# Compiling with `--test` builds a test runner instead,
+# which runs the tests in parallel and reports the results.
+$ rustc --test testing.rs && ./testing
+
+running 7 tests
+# {unordered}
+test tests::adding_a_negative_number_changes_the_result ... ok
+test tests::adds_two_numbers ... ok
+test tests::divides_two_numbers ... ok
+test tests::dividing_by_zero_is_an_error ... ok
+test tests::fizzbuzz_many_numbers ... ignored, slow
+test tests::fizzbuzz_table ... ok
+test tests::popping_an_empty_stack_panics - should panic ... ok
+# {end}
+
+# {match} test result: ok. 6 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in *s
+test result: ok. 6 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.02s
+
+# The ignored tests are run with `--ignored`.
+$ ./testing --ignored
+
+running 1 test
+test tests::fizzbuzz_many_numbers ... ok
+
+# {match} test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 6 filtered out; finished in *s
+test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 6 filtered out; finished in 0.01s
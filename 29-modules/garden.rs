@@ -0,0 +1,17 @@
+// This file holds the `garden` module, declared with `mod garden;` in
+// `modules.rs`. Its contents are the body of the module, so there is no
+// `mod garden { ... }` around them.
+
+// A module file can declare submodules of its own. The code of
+// `garden::vegetables` is in `garden/vegetables.rs`, in a directory named
+// after this module.
+pub mod vegetables;
+
+// Within a module, paths are relative to the module itself, so the
+// submodule can be used by its name.
+use vegetables::Asparagus;
+
+pub fn plant_and_harvest(count: u32) -> Vec<Asparagus> {
+    println!("Planting {} asparagus", count);
+    (1..=count).map(|i| Asparagus::new(i * 10)).collect()
+}
@@ -0,0 +1,20 @@
+// This file holds the `garden::vegetables` module, declared with
+// `pub mod vegetables;` in `garden.rs`.
+
+pub struct Asparagus {
+    pub height_cm: u32,
+}
+
+impl Asparagus {
+    pub fn new(height_cm: u32) -> Asparagus {
+        Asparagus { height_cm }
+    }
+}
+
+// An absolute path starting with `crate::` works from any module. Here it
+// reaches the `text` module that the crate root loaded from
+// `shared/text.rs`.
+pub fn describe(asparagus: &Asparagus) -> String {
+    let height = format!("{}cm", asparagus.height_cm);
+    format!("This asparagus is {}", crate::text::shout(&height))
+}
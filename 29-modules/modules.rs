@@ -1,7 +1,142 @@
-// Title: *Coming* Modules
+// Title: Modules
 // Language: Rust
+//
+// Modules organize the code of a crate into a tree of namespaces, and control
+// which items are visible outside of them. Items are private by default, and
+// are made public with `pub`. A module can be written inline, or in a file of
+// its own, which is why this chapter spans several files: `modules.rs` is the
+// crate root, the `garden` module lives in `garden.rs` with its submodule in
+// `garden/vegetables.rs`, and `shared/text.rs` is loaded with `#[path]`.
+//
+// Only the crate root is passed to the compiler, which finds the other files
+// through the `mod` declarations. Since the 2018 edition, a `use` path inside
+// a submodule may start with the name of a child module, like
+// `use vegetables::Asparagus;` in `garden.rs`. In the 2015 edition, `use`
+// paths always start from the crate root, so compile with
+// `rustc --edition 2021 modules.rs`.
+
+// `mod garden;` without a body declares a module whose code is in another
+// file. The compiler looks for it in `garden.rs` next to this file, or in
+// `garden/mod.rs`.
+mod garden;
+
+// The `#[path]` attribute loads a module from a file that doesn't follow
+// these naming rules. Here the `text` module is read from `shared/text.rs`.
+#[path = "shared/text.rs"]
+mod text;
+
+// An inline module is declared with `mod` followed by a block. Modules can
+// be nested, forming the module tree of the crate.
+mod restaurant {
+
+    // A private item is visible within the module where it's defined and in
+    // all of its descendants. `pub` makes an item visible to the parent
+    // module as well, and from there to anyone who can see the parent.
+    pub mod front_of_house {
+        pub fn add_to_waitlist(name: &str) {
+            println!("Added {} to the waitlist", name);
+            seat_at_table(name);
+        }
+
+        // `seat_at_table` is private: it can be called from within
+        // `front_of_house`, but not from `main`.
+        fn seat_at_table(name: &str) {
+            println!("Seated {}", name);
+        }
+
+        pub fn serve_order() {
+            println!("Order served");
+        }
+    }
+
+    pub mod back_of_house {
+
+        // Making a struct public doesn't make its fields public. Each field
+        // is private unless marked with `pub`. Since `seasonal_fruit` is
+        // private, a `Breakfast` can only be created through the public
+        // constructor `summer`.
+        pub struct Breakfast {
+            pub toast: String,
+            seasonal_fruit: String,
+        }
+
+        impl Breakfast {
+            pub fn summer(toast: &str) -> Breakfast {
+                Breakfast {
+                    toast: String::from(toast),
+                    seasonal_fruit: String::from("peaches"),
+                }
+            }
+
+            pub fn fruit(&self) -> &str {
+                &self.seasonal_fruit
+            }
+        }
+
+        // In contrast, all variants of a public enum are public.
+        #[derive(Debug)]
+        pub enum Appetizer {
+            Soup,
+            Salad,
+        }
+
+        // `super` starts a path in the parent module, like `..` in a file
+        // system. `self` starts a path in the current module.
+        pub fn fix_incorrect_order() {
+            self::cook_order();
+            super::front_of_house::serve_order();
+        }
+
+        fn cook_order() {
+            println!("Order cooked");
+        }
+    }
+
+    // `pub(crate)` makes an item visible everywhere within the current
+    // crate, but not to other crates that depend on it. `pub(super)` would
+    // only make it visible to the parent module.
+    pub(crate) fn opening_hours() -> &'static str {
+        "9:00 - 22:00"
+    }
+
+    // `pub use` *re-exports* an item: it becomes available under this
+    // module as well, so callers can write `restaurant::add_to_waitlist`
+    // without knowing about `front_of_house`.
+    pub use self::front_of_house::add_to_waitlist;
+}
+
+// `use` brings a path into scope, so we don't have to repeat it. Braces
+// import several items from the same module, with `self` importing the
+// module itself. `as` gives an imported item a different name.
+use restaurant::back_of_house::{self, Appetizer, Breakfast};
+use garden::vegetables::Asparagus as Veggie;
 
 fn main() {
-    // Modules
-    println!("Modules");
+
+    // Items are referred to by their path. This is an absolute path from
+    // the crate root, written with `crate::`.
+    crate::restaurant::front_of_house::add_to_waitlist("Alice");
+
+    // The re-exported function has a shorter path.
+    restaurant::add_to_waitlist("Bob");
+
+    // Public fields can be read and changed, private fields only through
+    // the methods of the struct.
+    let mut meal = Breakfast::summer("rye");
+    meal.toast = String::from("wheat");
+    println!("I'd like {} toast with {}, please", meal.toast, meal.fruit());
+
+    let appetizers = [Appetizer::Soup, Appetizer::Salad];
+    println!("Appetizers: {:?}", appetizers);
+
+    back_of_house::fix_incorrect_order();
+    println!("Opening hours: {}", restaurant::opening_hours());
+
+    // Items from the modules in other files are used just the same.
+    let harvest = garden::plant_and_harvest(3);
+    println!("Harvested {} asparagus", harvest.len());
+
+    let veggie = Veggie::new(12);
+    println!("{}", garden::vegetables::describe(&veggie));
+    println!("{}", text::shout("modules"));
 }
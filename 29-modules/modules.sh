@@ -1,4 +1,16 @@
 # Language: shell
-# Modules
-$ rustc modules.rs
+$ rustc --edition 2021 modules.rs
 $ ./modules
+Added Alice to the waitlist
+Seated Alice
+Added Bob to the waitlist
+Seated Bob
+I'd like wheat toast with peaches, please
+Appetizers: [Soup, Salad]
+Order cooked
+Order served
+Opening hours: 9:00 - 22:00
+Planting 3 asparagus
+Harvested 3 asparagus
+This asparagus is 12CM!
+MODULES!
@@ -0,0 +1,6 @@
+// This file holds the `text` module. Its name doesn't match the file, so
+// the crate root loads it with `#[path = "shared/text.rs"]`.
+
+pub fn shout(text: &str) -> String {
+    format!("{}!", text.to_uppercase())
+}
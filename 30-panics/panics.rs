@@ -0,0 +1,172 @@
+// Title: Panics
+// Language: Rust
+//
+// Errors that a program is expected to handle are returned as a `Result`, as
+// shown in the error handling chapter. A *panic* is for the other kind:
+// bugs and broken invariants that the program can't sensibly recover from,
+// like indexing past the end of a vector or calling `unwrap()` on an `Err`.
+// A panic prints a message, *unwinds* the stack of the current thread while
+// running the destructors of all values on it, and then ends the thread. If
+// that thread is the main thread, the whole program ends.
+//
+// This chapter shows how to customize the panic message, how to stop a
+// panic from spreading at a thread boundary, and how a program reports
+// failure through its exit status.
+
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::thread;
+
+fn main() {
+
+    // The program can be run with an argument, `panic` or `exit`, to see the
+    // two ways of ending it abnormally described at the end of the chapter.
+    match env::args().nth(1).as_deref() {
+        Some("panic") => uncaught_panic(),
+        Some("exit") => exit_with_code(),
+        _ => {}
+    }
+
+    // ### Panic Hooks
+
+    // When a panic occurs, the *panic hook* is called to report it before the
+    // stack is unwound. The default hook prints the name of the thread, the
+    // location and the message to stderr. `panic::set_hook` replaces it with
+    // our own function, which receives the message (the *payload*) and the
+    // location of the panic.
+    panic::set_hook(Box::new(|info| {
+        let message = panic_message(info.payload());
+        match info.location() {
+            Some(location) => eprintln!(
+                "panic hook: '{}' at {}:{}",
+                message,
+                location.file(),
+                location.line()
+            ),
+            None => eprintln!("panic hook: '{}'", message),
+        }
+    }));
+
+    // ### Panics and Threads
+
+    // `panic!` starts a panic with a formatted message. A panic only ends the
+    // thread it occurs in. When another thread joins a thread that panicked,
+    // `join()` returns an `Err` containing the panic payload.
+    let handle = thread::spawn(|| {
+        let answer = 41;
+        if answer != 42 {
+            panic!("expected 42, got {}", answer);
+        }
+        answer
+    });
+
+    match handle.join() {
+        Ok(answer) => println!("The thread returned {}", answer),
+        Err(payload) => {
+            println!("The thread panicked: {}", panic_message(&*payload))
+        }
+    }
+
+    // ### Catching Panics
+
+    // `panic::catch_unwind` runs a closure and catches a panic that unwinds
+    // out of it, returning `Err` with the payload instead. It is meant for
+    // boundaries where a panic must not spread, like a worker thread that
+    // runs jobs for others: one buggy job shouldn't bring down the worker.
+    // It is not a way to handle ordinary errors, which should use `Result`.
+    let jobs: Vec<(i32, i32)> = vec![(10, 2), (1, 0), (9, 3)];
+
+    let worker = thread::spawn(move || {
+        let mut completed = 0;
+        for (x, y) in jobs {
+            // `catch_unwind` requires the closure to be *unwind safe*, so
+            // that no broken state can be observed after a panic. The
+            // compiler can't always prove this, and `AssertUnwindSafe` lets
+            // us promise it ourselves.
+            let result = panic::catch_unwind(AssertUnwindSafe(|| x / y));
+            match result {
+                Ok(quotient) => {
+                    println!("Job {} / {} = {}", x, y, quotient);
+                    completed += 1;
+                }
+                Err(payload) => {
+                    let message = panic_message(&*payload);
+                    println!("Job {} / {} failed: {}", x, y, message);
+                }
+            }
+        }
+        completed
+    });
+
+    // The worker survived the failing job and finished the others.
+    println!("Completed jobs: {}", worker.join().unwrap());
+
+    // ### Backtraces
+
+    // A backtrace lists the function calls that led to the panic, which
+    // helps to find the cause. The default hook prints one when the
+    // `RUST_BACKTRACE` environment variable is set to `1`, or to `full` for
+    // more detail, as in `RUST_BACKTRACE=1 ./panics panic`. The same
+    // variable controls `Backtrace::capture`, which captures a backtrace
+    // from anywhere in the program, unless `RUST_LIB_BACKTRACE` overrides it.
+    let backtrace = Backtrace::capture();
+    println!("Backtrace status: {:?}", backtrace.status());
+
+    // `panic::take_hook` removes our hook and restores the default one, so
+    // any later panic is reported as usual.
+    let _ = panic::take_hook();
+
+    println!("Done");
+}
+
+// The payload of a panic is a `Box<dyn Any + Send>`. Panics started with a
+// literal message carry a `&str`, and those with format arguments carry a
+// `String`, so we try to downcast to both.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+// ### Exit Status
+
+// A program reports success or failure to whoever started it through its
+// *exit status*, where `0` means success. A panic in the main thread ends
+// the program with the status `101`, after the default hook has printed its
+// message. (Returning an `Err` from `main` ends it with the status `1`.)
+//
+// Both functions below never return, which their return type `!` states.
+//
+// The settings below have no `port`, so `expect` always panics.
+fn uncaught_panic() -> ! {
+    let settings = [("name", "panics"), ("level", "3")];
+    let port = settings.iter().find(|(key, _)| *key == "port");
+    let (_, port) = port.expect("the port setting is missing");
+    println!("Using port {}", port);
+    process::exit(0);
+}
+
+// `process::exit` ends the program immediately with the given status.
+// Unlike a panic, it doesn't unwind the stack, so destructors don't run:
+// the `Noisy` value below is never dropped, and buffered output that
+// hasn't been flushed is lost.
+struct Noisy;
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        println!("Noisy dropped");
+    }
+}
+
+fn exit_with_code() -> ! {
+    let _noisy = Noisy;
+    eprintln!("Invalid configuration, exiting");
+    process::exit(2);
+}
@@ -0,0 +1,32 @@
+# Language: shell
+$ rustc panics.rs
+$ ./panics
+The thread panicked: expected 42, got 41
+Job 10 / 2 = 5
+Job 1 / 0 failed: attempt to divide by zero
+Job 9 / 3 = 3
+Completed jobs: 2
+Backtrace status: Disabled
+Done
+# The panic hook prints to stderr.
+# {stderr}
+panic hook: 'expected 42, got 41' at panics.rs:61
+panic hook: 'attempt to divide by zero' at panics.rs:89
+
+# The default hook prints to stderr as well, and names the thread that
+# panicked. Newer versions of Rust also print the id of the thread, which
+# changes from run to run. The program exits with the status 101.
+$ ./panics panic
+# {stderr}
+
+# {match} thread 'main'* panicked at panics.rs:151:26:
+thread 'main' panicked at panics.rs:151:26:
+the port setting is missing
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+# {exit} 101
+
+# `Noisy dropped` is never printed, since `process::exit` skips destructors.
+$ ./panics exit
+# {stderr}
+Invalid configuration, exiting
+# {exit} 2
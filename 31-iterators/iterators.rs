@@ -0,0 +1,257 @@
+// Title: Iterators
+// Language: Rust
+//
+// An iterator produces a sequence of values, one at a time. Iterators are
+// behind every `for` loop, and they come with a large set of methods that
+// transform sequences step by step, like `map` and `filter`, before
+// consuming them with methods like `sum` or `collect`. Iterators are lazy:
+// nothing happens until a value is asked for.
+
+use std::collections::{BTreeMap, HashSet};
+
+// ### The `Iterator` Trait
+
+// An iterator is any type that implements the `Iterator` trait. It has a
+// single required method, `next`, which returns `Some(value)` for the next
+// value of the sequence, or `None` once the sequence is over. The type of
+// the values is the associated type `Item`. All other methods of the trait,
+// like `map` and `sum`, have default implementations built on `next`.
+//
+// This iterator produces the Fibonacci numbers. It keeps the two upcoming
+// numbers as its state. The numbers grow quickly, and after 94 of them the
+// next one no longer fits in a `u64`. `checked_add` returns `None` when an
+// addition would overflow, and from then on the iterator returns `None` as
+// well, which ends the sequence.
+struct Fibonacci {
+    current: Option<u64>,
+    next: Option<u64>,
+}
+
+impl Fibonacci {
+    fn new() -> Fibonacci {
+        Fibonacci {
+            current: Some(0),
+            next: Some(1),
+        }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    // `?` returns `None` from `next` once `current` is `None`.
+    fn next(&mut self) -> Option<u64> {
+        let value = self.current?;
+        self.current = self.next;
+        self.next = self.next.and_then(|next| next.checked_add(value));
+        Some(value)
+    }
+}
+
+// ### Implementing `IntoIterator`
+
+// A `for` loop works with any type that implements `IntoIterator`, which
+// converts a value into an iterator. Collections usually implement it
+// twice: for the collection itself, which iterates over owned values and
+// consumes the collection, and for a reference to it, which iterates over
+// references and leaves the collection usable.
+struct Playlist {
+    songs: Vec<String>,
+}
+
+// Iterating over a `Playlist` by value hands out the songs themselves. We
+// reuse the iterator of the `Vec` inside.
+impl IntoIterator for Playlist {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.songs.into_iter()
+    }
+}
+
+// Iterating over a `&Playlist` hands out references to the songs.
+impl<'a> IntoIterator for &'a Playlist {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.songs.iter()
+    }
+}
+
+fn main() {
+
+    // Calling `next` by hand shows how an iterator works. This is what a
+    // `for` loop does behind the scenes, until `next` returns `None`.
+    let numbers = [1, 2, 3];
+    let mut iter = numbers.iter();
+    println!("{:?}", iter.next());
+    println!("{:?}", iter.next());
+    println!("{:?}", iter.next());
+    println!("{:?}", iter.next());
+
+    // We use `take` to limit `Fibonacci` to the first ten numbers. A `for`
+    // loop accepts any iterator.
+    for number in Fibonacci::new().take(10) {
+        print!("{} ", number);
+    }
+    println!();
+
+    // A collection can be iterated in three ways: `iter()` borrows each
+    // element, `iter_mut()` borrows each element mutably, and
+    // `into_iter()` takes ownership of the elements.
+    let mut scores = vec![10, 20, 30];
+    for score in scores.iter_mut() {
+        *score += 1;
+    }
+    let total: i32 = scores.iter().sum();
+    println!("Scores: {:?}, total: {}", scores, total);
+
+    // ### Adapters
+
+    // *Adapters* are methods that take an iterator and return a new one.
+    // `map` transforms each value, and `filter` keeps the values for which
+    // the closure returns `true`. Adapters are lazy, so the chain below does
+    // no work until `collect` asks for the values.
+    let squares_of_odds: Vec<u32> = (1..10)
+        .filter(|n| n % 2 == 1)
+        .map(|n| n * n)
+        .collect();
+    println!("Squares of odd numbers: {:?}", squares_of_odds);
+
+    // `fold` combines all values into one, starting from an initial value.
+    // Here it turns a list of digits into a number. `sum` and `product` are
+    // shortcuts for common folds.
+    let digits = [4, 2, 7];
+    let number = digits.iter().fold(0, |acc, digit| acc * 10 + digit);
+    println!("{:?} as a number: {}", digits, number);
+
+    // `zip` walks two iterators in step, yielding pairs. It stops as soon as
+    // either of them ends.
+    let names = ["Alice", "Bob", "Carol"];
+    let ages = [31, 27];
+    for (name, age) in names.iter().zip(ages.iter()) {
+        println!("{} is {}", name, age);
+    }
+
+    // `chain` yields all values of one iterator, then those of another.
+    let chained: Vec<i32> = (1..3).chain(7..9).collect();
+    println!("Chained: {:?}", chained);
+
+    // `take_while` yields values as long as the closure returns `true`, and
+    // stops at the first one for which it doesn't. Unlike `filter`, it
+    // doesn't look at the rest of the sequence, so it also works with endless
+    // iterators, like the range `0..` without an end.
+    let small: Vec<u64> = Fibonacci::new().take_while(|&n| n < 100).collect();
+    println!("Fibonacci numbers below 100: {:?}", small);
+
+    // `flat_map` maps each value to an iterator and flattens the results
+    // into a single sequence.
+    let sentences = ["the quick fox", "jumps over"];
+    let words: Vec<&str> = sentences.iter().flat_map(|s| s.split(' ')).collect();
+    println!("Words: {:?}", words);
+
+    // `enumerate` pairs each value with its index, and `rev` reverses an
+    // iterator that can be walked from both ends.
+    for (i, word) in words.iter().rev().enumerate() {
+        println!("{}: {}", i, word);
+    }
+
+    // Consumers other than `collect` and `fold` answer questions about the
+    // sequence. They stop early when the answer is known, so `find` only
+    // computes the first few Fibonacci numbers here, and `any` and `find`
+    // work with endless iterators too.
+    let even_fibonacci = Fibonacci::new().skip(1).find(|n| n % 2 == 0);
+    println!("First even Fibonacci number after 0: {:?}", even_fibonacci);
+
+    let long_word = words.iter().any(|w| w.len() > 4);
+    println!("Any word longer than 4 letters: {}", long_word);
+    println!("Longest word: {:?}", words.iter().max_by_key(|w| w.len()));
+
+    // The Fibonacci sequence ends before the numbers overflow. `count`
+    // consumes the whole iterator, and `last` returns its final value.
+    println!("Fibonacci numbers in a u64: {}", Fibonacci::new().count());
+    println!("The largest one: {:?}", Fibonacci::new().last());
+
+    // ### Iterating Over Our Own Type
+
+    // With both `IntoIterator` implementations, a `Playlist` works with
+    // `for` loops just like a `Vec`.
+    let playlist = Playlist {
+        songs: vec![
+            String::from("Intro"),
+            String::from("Theme"),
+            String::from("Outro"),
+        ],
+    };
+
+    // Iterating over `&playlist` borrows it...
+    for song in &playlist {
+        print!("{} ", song);
+    }
+    println!();
+
+    // ...while iterating over `playlist` consumes it. It can't be used after
+    // this loop.
+    let mut owned = Vec::new();
+    for song in playlist {
+        owned.push(song.to_uppercase());
+    }
+    println!("{:?}", owned);
+
+    // ### Peeking
+
+    // `peekable` wraps an iterator so that `peek` can look at the next value
+    // without consuming it. This helps when the next value decides what to
+    // do with the current one, like when grouping runs of equal characters.
+    // `next_if_eq` consumes the next value only when it's equal to the given
+    // one.
+    let text = "aaabccdddd";
+    let mut chars = text.chars().peekable();
+    let mut encoded = String::new();
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        while chars.next_if_eq(&c).is_some() {
+            count += 1;
+        }
+        encoded.push_str(&format!("{}{}", c, count));
+    }
+    println!("{} encodes to {}", text, encoded);
+
+    // `peek` returns a reference to the next value, or `None` at the end.
+    let mut numbers = [1, 2, 3].iter().peekable();
+    while let Some(n) = numbers.next() {
+        match numbers.peek() {
+            Some(next) => print!("{} then {}, ", n, next),
+            None => println!("{} is last", n),
+        }
+    }
+
+    // ### Collecting
+
+    // `collect` can build any collection that implements `FromIterator`.
+    // The target type is given with a type annotation, or with the
+    // *turbofish* syntax `collect::<T>()`. `_` lets the compiler infer the
+    // element type.
+    let letters = ["b", "a", "c", "a"];
+    let joined: String = letters.iter().cloned().collect();
+    let unique: HashSet<_> = letters.iter().collect();
+    println!("Joined: {}, unique letters: {}", joined, unique.len());
+
+    // Iterators of pairs collect into maps. A `BTreeMap` keeps its keys
+    // sorted.
+    let lengths: BTreeMap<&str, usize> = words.iter().map(|w| (*w, w.len())).collect();
+    println!("Word lengths: {:?}", lengths);
+
+    // An iterator of `Result`s can be collected into a `Result` of a
+    // collection. It's `Ok` if all values are `Ok`, and otherwise the first
+    // `Err`, at which point collecting stops.
+    let good: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    let bad = ["1", "two", "3"]
+        .iter()
+        .map(|s| s.parse::<i32>())
+        .collect::<Result<Vec<_>, _>>();
+    println!("{:?}", good);
+    println!("{:?}", bad);
+}
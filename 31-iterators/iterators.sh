@@ -0,0 +1,34 @@
+# Language: shell
+$ rustc iterators.rs
+$ ./iterators
+Some(1)
+Some(2)
+Some(3)
+None
+0 1 1 2 3 5 8 13 21 34
+Scores: [11, 21, 31], total: 63
+Squares of odd numbers: [1, 9, 25, 49, 81]
+[4, 2, 7] as a number: 427
+Alice is 31
+Bob is 27
+Chained: [1, 2, 7, 8]
+Fibonacci numbers below 100: [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]
+Words: ["the", "quick", "fox", "jumps", "over"]
+0: over
+1: jumps
+2: fox
+3: quick
+4: the
+First even Fibonacci number after 0: Some(2)
+Any word longer than 4 letters: true
+Longest word: Some("jumps")
+Fibonacci numbers in a u64: 94
+The largest one: Some(12200160415121876738)
+Intro Theme Outro
+["INTRO", "THEME", "OUTRO"]
+aaabccdddd encodes to a3b1c2d4
+1 then 2, 2 then 3, 3 is last
+Joined: baca, unique letters: 3
+Word lengths: {"fox": 3, "jumps": 5, "over": 4, "quick": 5, "the": 3}
+Ok([1, 2, 3])
+Err(ParseIntError { kind: InvalidDigit })
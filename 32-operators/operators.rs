@@ -0,0 +1,282 @@
+// Title: Operator Overloading
+// Language: Rust
+//
+// Operators like `+`, `*` and `[]` are shorthand for calls to methods of
+// traits in the `std::ops` module. `a + b` calls `Add::add(a, b)`, `-a`
+// calls `Neg::neg(a)`, and so on. Implementing these traits for our own
+// types lets them be used with the operators, just like the built-in
+// numbers. Comparison operators work the same way, through the traits of
+// `std::cmp`.
+//
+// Here we build a two-dimensional vector type, and a 2x2 matrix on top of
+// it.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub};
+
+// Like the `Point` of the structs chapter, `Vector2` is a named-field
+// struct. It derives `Clone` and `Copy`, so that the operators, which take
+// their operands by value, don't move the vectors away.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vector2 {
+    x: f64,
+    y: f64,
+}
+
+impl Vector2 {
+    fn new(x: f64, y: f64) -> Vector2 {
+        Vector2 { x, y }
+    }
+
+    fn length(&self) -> f64 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    fn dot(self, other: Vector2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl fmt::Display for Vector2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// ### Arithmetic Operators
+
+// `Add` has an associated type `Output`, the type of the result. Adding two
+// vectors gives a vector.
+impl Add for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+// The right-hand side doesn't have to be of the same type. `Mul<f64>`
+// defines `vector * number`, which scales the vector.
+impl Mul<f64> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, factor: f64) -> Vector2 {
+        Vector2::new(self.x * factor, self.y * factor)
+    }
+}
+
+// `number * vector` is a different operation, with `f64` on the left. It is
+// implemented on `f64`, which is allowed because `Vector2` is our own type.
+impl Mul<Vector2> for f64 {
+    type Output = Vector2;
+
+    fn mul(self, vector: Vector2) -> Vector2 {
+        vector * self
+    }
+}
+
+// Unary operators take only `self`. `Neg` is the unary minus.
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+// Compound assignment operators like `+=` have their own traits. They take
+// `&mut self` and change the value in place instead of returning a new one.
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Vector2) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+// ### Indexing
+
+// `Index` defines `vector[i]` for reading, and `IndexMut` for writing. Both
+// return a reference into the value. Like indexing a slice out of bounds,
+// an invalid index panics.
+impl Index<usize> for Vector2 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vector2 index out of bounds: {}", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector2 {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vector2 index out of bounds: {}", index),
+        }
+    }
+}
+
+// ### Comparisons
+
+// `==` and `!=` come from `PartialEq`, which we derived. `<`, `>`, `<=` and
+// `>=` come from `PartialOrd`, whose `partial_cmp` method returns the
+// `Ordering` of two values, or `None` when they can't be compared.
+//
+// We order vectors by their length. Two different vectors of the same
+// length are neither smaller, greater nor equal, so they are incomparable.
+// This keeps `PartialOrd` consistent with `PartialEq`, as it must be.
+impl PartialOrd for Vector2 {
+    fn partial_cmp(&self, other: &Vector2) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        match self.length().partial_cmp(&other.length()) {
+            Some(Ordering::Equal) => None,
+            ordering => ordering,
+        }
+    }
+}
+
+// ### A 2x2 Matrix
+
+// The matrix stores its two rows as vectors, so it can build on the
+// operators defined above.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Matrix2 {
+    rows: [Vector2; 2],
+}
+
+impl Matrix2 {
+    fn new(a: f64, b: f64, c: f64, d: f64) -> Matrix2 {
+        Matrix2 {
+            rows: [Vector2::new(a, b), Vector2::new(c, d)],
+        }
+    }
+
+    fn identity() -> Matrix2 {
+        Matrix2::new(1.0, 0.0, 0.0, 1.0)
+    }
+
+    fn column(&self, index: usize) -> Vector2 {
+        Vector2::new(self.rows[0][index], self.rows[1][index])
+    }
+}
+
+// Indexing a matrix returns a row. Since rows can be indexed as well,
+// `matrix[0][1]` reads the element in the first row and second column.
+impl Index<usize> for Matrix2 {
+    type Output = Vector2;
+
+    fn index(&self, row: usize) -> &Vector2 {
+        &self.rows[row]
+    }
+}
+
+// Multiplying a matrix with a vector transforms the vector. Each element of
+// the result is the dot product of a row with the vector.
+impl Mul<Vector2> for Matrix2 {
+    type Output = Vector2;
+
+    fn mul(self, vector: Vector2) -> Vector2 {
+        Vector2::new(self[0].dot(vector), self[1].dot(vector))
+    }
+}
+
+// Multiplying two matrices combines their transformations. Each element is
+// the dot product of a row of the left matrix with a column of the right.
+impl Mul for Matrix2 {
+    type Output = Matrix2;
+
+    fn mul(self, other: Matrix2) -> Matrix2 {
+        Matrix2::new(
+            self[0].dot(other.column(0)),
+            self[0].dot(other.column(1)),
+            self[1].dot(other.column(0)),
+            self[1].dot(other.column(1)),
+        )
+    }
+}
+
+impl fmt::Display for Matrix2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} {}]", self[0], self[1])
+    }
+}
+
+fn main() {
+
+    let a = Vector2::new(1.0, 2.0);
+    let b = Vector2::new(3.0, -1.0);
+
+    // The operators call the trait methods we implemented.
+    println!("a + b = {}", a + b);
+    println!("a - b = {}", a - b);
+    println!("a * 3 = {}", a * 3.0);
+    println!("2 * b = {}", 2.0 * b);
+    println!("-a = {}", -a);
+
+    // Operators can be combined, and follow the usual precedence rules.
+    println!("a + b * 2 = {}", a + b * 2.0);
+
+    let mut position = Vector2::new(0.0, 0.0);
+    let velocity = Vector2::new(0.5, 1.0);
+    for _ in 0..4 {
+        position += velocity;
+    }
+    println!("Position after 4 steps: {}", position);
+
+    // Indexing reads and writes the components.
+    position[1] = 10.0;
+    println!("x = {}, y = {}", position[0], position[1]);
+
+    // Comparisons use the lengths of the vectors.
+    let short = Vector2::new(1.0, 0.0);
+    let long = Vector2::new(0.0, 5.0);
+    let also_short = Vector2::new(0.0, 1.0);
+    println!("{} < {}: {}", short, long, short < long);
+    println!("{} >= {}: {}", long, short, long >= short);
+
+    // Vectors of the same length are incomparable, so even `<=` is false.
+    println!("{} <= {}: {}", short, also_short, short <= also_short);
+    println!(
+        "{} and {} compare as {:?}",
+        short,
+        also_short,
+        short.partial_cmp(&also_short)
+    );
+
+    // A matrix that rotates by 90 degrees counterclockwise turns the x axis
+    // into the y axis.
+    let rotate = Matrix2::new(0.0, -1.0, 1.0, 0.0);
+    let point = Vector2::new(2.0, 1.0);
+    println!("Rotated {} to {}", point, rotate * point);
+
+    // Rotating twice is the same as multiplying the two rotations first.
+    let rotate_twice = rotate * rotate;
+    println!("Rotated {} twice to {}", point, rotate_twice * point);
+
+    // Four rotations by 90 degrees bring everything back to where it was.
+    let full_turn = rotate_twice * rotate_twice;
+    let is_identity = full_turn == Matrix2::identity();
+    println!("A full turn is the identity: {}", is_identity);
+
+    // Scaling combines with rotating, and the element at row 0, column 1 is
+    // read with two indexing operators.
+    let scale = Matrix2::new(2.0, 0.0, 0.0, 3.0);
+    let combined = scale * rotate;
+    println!("Scale after rotate: {}", combined);
+    println!("combined[0][1] = {}", combined[0][1]);
+}
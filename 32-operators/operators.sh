@@ -0,0 +1,20 @@
+# Language: shell
+$ rustc operators.rs
+$ ./operators
+a + b = (4, 1)
+a - b = (-2, 3)
+a * 3 = (3, 6)
+2 * b = (6, -2)
+-a = (-1, -2)
+a + b * 2 = (7, 0)
+Position after 4 steps: (2, 4)
+x = 2, y = 10
+(1, 0) < (0, 5): true
+(0, 5) >= (1, 0): true
+(1, 0) <= (0, 1): false
+(1, 0) and (0, 1) compare as None
+Rotated (2, 1) to (-1, 2)
+Rotated (2, 1) twice to (-2, -1)
+A full turn is the identity: true
+Scale after rotate: [(0, -2) (3, 0)]
+combined[0][1] = -2
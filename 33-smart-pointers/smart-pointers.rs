@@ -0,0 +1,268 @@
+// Title: Smart Pointers
+// Language: Rust
+//
+// The pointers chapter introduced `Box`, `Rc`, `Arc` and `RefCell`. This
+// chapter builds on them. `Weak` is the non-owning counterpart of `Rc`,
+// needed for data structures where values point back at their owners, like
+// a tree whose nodes know their parent. We also look at the other cell
+// types, `Cell` and `OnceCell`, and at `Cow`, which borrows data as long as
+// it can and only clones it when it must.
+
+use std::borrow::Cow;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::rc::{Rc, Weak};
+
+// ### A Tree with Parent Links
+
+// Each node owns its children through `Rc`, so a subtree stays alive as
+// long as someone holds on to it. The nodes are wrapped in a `RefCell`, so
+// that children can be added to a node that is shared.
+//
+// A child also needs to find its parent. If it held an `Rc` to the parent,
+// parent and child would keep each other alive forever. Instead it holds a
+// `Weak` pointer, which refers to the parent without owning it.
+struct Node {
+    name: String,
+    parent: Option<Weak<RefCell<Node>>>,
+    children: Vec<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    fn new(name: &str) -> Rc<RefCell<Node>> {
+        Rc::new(RefCell::new(Node {
+            name: name.to_string(),
+            parent: None,
+            children: Vec::new(),
+        }))
+    }
+
+    // Since a `Weak` doesn't keep its value alive, the value may be gone. To
+    // use it, `upgrade` turns the `Weak` into an `Option<Rc>`, which is
+    // `None` once the value has been dropped.
+    fn parent(&self) -> Option<Rc<RefCell<Node>>> {
+        self.parent.as_ref().and_then(|weak| weak.upgrade())
+    }
+}
+
+// Printing a message when a node is dropped shows when its memory is freed.
+impl Drop for Node {
+    fn drop(&mut self) {
+        println!("Dropping {}", self.name);
+    }
+}
+
+// `Rc::downgrade` creates a `Weak` pointer from an `Rc`. It increases the
+// *weak count* of the node, but not its *strong count*. Only the strong
+// count keeps a value alive.
+fn add_child(parent: &Rc<RefCell<Node>>, child: Rc<RefCell<Node>>) {
+    child.borrow_mut().parent = Some(Rc::downgrade(parent));
+    parent.borrow_mut().children.push(child);
+}
+
+fn parent_name(node: &Rc<RefCell<Node>>) -> String {
+    let parent = node.borrow().parent();
+    match parent {
+        Some(parent) => parent.borrow().name.clone(),
+        None => String::from("(none)"),
+    }
+}
+
+// Walks up the parent links to build the path from the root to a node.
+fn path(node: &Rc<RefCell<Node>>) -> String {
+    let mut names = vec![node.borrow().name.clone()];
+    let mut current = node.borrow().parent();
+    while let Some(parent) = current {
+        names.push(parent.borrow().name.clone());
+        current = parent.borrow().parent();
+    }
+    names.reverse();
+    names.join("/")
+}
+
+fn print_counts(label: &str, node: &Rc<RefCell<Node>>) {
+    println!(
+        "{}: strong = {}, weak = {}",
+        label,
+        Rc::strong_count(node),
+        Rc::weak_count(node)
+    );
+}
+
+// ### Reference Cycles
+
+// An `Rc` cycle is never freed: each value keeps the other one's strong
+// count above zero. `Person` shows this. Its `friend` can be either a
+// strong or a weak link.
+struct Person {
+    name: String,
+    friend: RefCell<Option<Rc<Person>>>,
+    weak_friend: RefCell<Weak<Person>>,
+}
+
+impl Person {
+    fn new(name: &str) -> Rc<Person> {
+        Rc::new(Person {
+            name: name.to_string(),
+            friend: RefCell::new(None),
+            weak_friend: RefCell::new(Weak::new()),
+        })
+    }
+}
+
+impl Drop for Person {
+    fn drop(&mut self) {
+        println!("Dropping {}", self.name);
+    }
+}
+
+fn main() {
+
+    let leaf = Node::new("leaf");
+    print_counts("leaf", &leaf);
+    println!("leaf's parent: {}", parent_name(&leaf));
+
+    {
+        let root = Node::new("root");
+        let branch = Node::new("branch");
+        add_child(&root, Rc::clone(&branch));
+        add_child(&branch, Rc::clone(&leaf));
+
+        // `leaf` is owned by the `leaf` variable and by `branch`, so its
+        // strong count is 2. `branch` has one weak reference, from `leaf`.
+        print_counts("leaf", &leaf);
+        print_counts("branch", &branch);
+        println!("leaf's parent: {}", parent_name(&leaf));
+        println!("Path: {}", path(&leaf));
+
+        // Dropping our handle to `branch` doesn't free it, since `root`
+        // still owns it.
+        drop(branch);
+        println!("Path after dropping the branch handle: {}", path(&leaf));
+
+        // At the end of this scope `root` is dropped. Its strong count
+        // reaches zero, so it's freed, and with it `branch`. The weak link
+        // from `leaf` doesn't prevent that.
+    }
+
+    // `leaf` survives, since the `leaf` variable still owns it, but its
+    // parent is gone.
+    print_counts("leaf", &leaf);
+    println!("leaf's parent: {}", parent_name(&leaf));
+    println!("Path: {}", path(&leaf));
+
+    // With strong links in both directions, neither `Alice` nor `Bob` is
+    // dropped at the end of the scope: their memory leaks.
+    {
+        let alice = Person::new("Alice");
+        let bob = Person::new("Bob");
+        *alice.friend.borrow_mut() = Some(Rc::clone(&bob));
+        *bob.friend.borrow_mut() = Some(Rc::clone(&alice));
+        println!(
+            "Strong cycle: Alice strong = {}, Bob strong = {}",
+            Rc::strong_count(&alice),
+            Rc::strong_count(&bob)
+        );
+    }
+    println!("End of the strong cycle scope");
+
+    // With weak links, the counts stay at 1, and both are dropped.
+    {
+        let carol = Person::new("Carol");
+        let dave = Person::new("Dave");
+        *carol.weak_friend.borrow_mut() = Rc::downgrade(&dave);
+        *dave.weak_friend.borrow_mut() = Rc::downgrade(&carol);
+        println!(
+            "Weak cycle: Carol strong = {}, Dave strong = {}",
+            Rc::strong_count(&carol),
+            Rc::strong_count(&dave)
+        );
+        let friend = carol.weak_friend.borrow().upgrade();
+        if let Some(friend) = friend {
+            println!("Carol's friend is {}", friend.name);
+        }
+    }
+    println!("End of the weak cycle scope");
+
+    // ### `Cell`
+
+    // `Cell` allows changing a value through a shared reference, like
+    // `RefCell`. Instead of lending out references to the value, it copies
+    // values in and out with `get` and `set`, so there are no borrows to
+    // track and no runtime checks. It suits small `Copy` values, like a
+    // counter in an otherwise immutable struct.
+    struct Page {
+        title: String,
+        views: Cell<u32>,
+    }
+
+    impl Page {
+        fn view(&self) {
+            self.views.set(self.views.get() + 1);
+        }
+    }
+
+    let page = Page {
+        title: String::from("Home"),
+        views: Cell::new(0),
+    };
+    page.view();
+    page.view();
+    page.view();
+    println!("{} was viewed {} times", page.title, page.views.get());
+
+    // `replace` sets a new value and returns the old one.
+    let old = page.views.replace(0);
+    println!("Reset from {} to {}", old, page.views.get());
+
+    // ### `OnceCell`
+
+    // `OnceCell` holds a value that is set at most once. `get_or_init`
+    // runs the initializer on the first call only, and afterwards returns
+    // the stored value. This makes it a good fit for values that are
+    // expensive to compute and may not be needed at all.
+    let greeting: OnceCell<String> = OnceCell::new();
+    println!("Before: {:?}", greeting.get());
+
+    for _ in 0..2 {
+        let value = greeting.get_or_init(|| {
+            println!("Computing the greeting");
+            String::from("Hello")
+        });
+        println!("Greeting: {}", value);
+    }
+
+    // A second `set` fails and hands the rejected value back.
+    println!("Set again: {:?}", greeting.set(String::from("Bye")));
+
+    // ### `Cow`
+
+    // `Cow` (*clone on write*) holds either borrowed or owned data. A
+    // function that only sometimes needs to modify its input can return
+    // the input itself when nothing changes, and only allocate a new
+    // `String` when it does.
+    fn normalize(input: &str) -> Cow<'_, str> {
+        if input.contains('\t') {
+            Cow::Owned(input.replace('\t', "    "))
+        } else {
+            Cow::Borrowed(input)
+        }
+    }
+
+    for input in ["no tabs here", "one\ttab"] {
+        let output = normalize(input);
+        let kind = match output {
+            Cow::Borrowed(_) => "borrowed",
+            Cow::Owned(_) => "owned",
+        };
+        println!("{:?} is {}", output, kind);
+    }
+
+    // `to_mut` gives mutable access, cloning borrowed data into owned data
+    // first. `into_owned` returns the owned data, cloning only if needed.
+    let mut text = normalize("hello");
+    text.to_mut().push_str(", world");
+    let owned: String = text.into_owned();
+    println!("{}", owned);
+
+    println!("End of main");
+}
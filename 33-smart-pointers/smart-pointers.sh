@@ -0,0 +1,34 @@
+# Language: shell
+$ rustc smart-pointers.rs
+$ ./smart-pointers
+leaf: strong = 1, weak = 0
+leaf's parent: (none)
+leaf: strong = 2, weak = 0
+branch: strong = 2, weak = 1
+leaf's parent: branch
+Path: root/branch/leaf
+Path after dropping the branch handle: root/branch/leaf
+Dropping root
+Dropping branch
+leaf: strong = 1, weak = 0
+leaf's parent: (none)
+Path: leaf
+Strong cycle: Alice strong = 2, Bob strong = 2
+End of the strong cycle scope
+Weak cycle: Carol strong = 1, Dave strong = 1
+Carol's friend is Dave
+Dropping Dave
+Dropping Carol
+End of the weak cycle scope
+Home was viewed 3 times
+Reset from 3 to 0
+Before: None
+Computing the greeting
+Greeting: Hello
+Greeting: Hello
+Set again: Err("Bye")
+"no tabs here" is borrowed
+"one    tab" is owned
+hello, world
+End of main
+Dropping leaf
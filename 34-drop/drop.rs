@@ -0,0 +1,219 @@
+// Title: Drop and RAII
+// Language: Rust
+//
+// When a value goes out of scope, Rust *drops* it: it runs the value's
+// destructor and frees its memory. This happens at a point known at compile
+// time, not when a garbage collector gets around to it. A type can run its
+// own code at that point by implementing the `Drop` trait.
+//
+// Tying a resource to the lifetime of a value like this is called *RAII*
+// (Resource Acquisition Is Initialization). Files are closed, locks are
+// released and memory is freed when the value that owns them is dropped,
+// so they can't be forgotten.
+
+use std::mem::{self, ManuallyDrop};
+
+// `Noisy` announces when it's created and when it's dropped, so we can
+// watch the order in which values are destroyed.
+struct Noisy {
+    name: &'static str,
+}
+
+impl Noisy {
+    fn new(name: &'static str) -> Noisy {
+        println!("  create {}", name);
+        Noisy { name }
+    }
+}
+
+// `drop` is called automatically, right before the value's memory is freed.
+// It takes `&mut self`, and it can't be called directly: use `mem::drop`
+// for that, as shown below.
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        println!("  drop {}", self.name);
+    }
+}
+
+// A struct whose fields have destructors of their own.
+struct Pair {
+    first: Noisy,
+    second: Noisy,
+}
+
+impl Drop for Pair {
+    fn drop(&mut self) {
+        let (first, second) = (self.first.name, self.second.name);
+        println!("  drop pair of {} and {}", first, second);
+    }
+}
+
+// Taking a parameter by value moves it into the function, which drops it
+// at its end.
+fn consume(value: Noisy) {
+    println!("  consuming {}", value.name);
+}
+
+// ### Scope Guards
+
+// A *scope guard* runs cleanup code when it's dropped. It holds a closure,
+// and its `Drop` implementation calls it. Since drop happens on every way
+// out of a scope, whether that's the end of the block, an early `return`,
+// a `?` or a panic, the cleanup can't be skipped. This is what `defer`
+// does in Go.
+struct Guard<F: FnOnce()> {
+    cleanup: Option<F>,
+}
+
+impl<F: FnOnce()> Guard<F> {
+    // Disarms the guard, so the cleanup doesn't run, e.g. once an operation
+    // has succeeded and there is nothing to undo.
+    fn dismiss(mut self) {
+        self.cleanup = None;
+    }
+}
+
+// `FnOnce` closures can only be called by value, but `drop` only has
+// `&mut self`. `Option::take` moves the closure out of the guard, leaving
+// `None` behind.
+impl<F: FnOnce()> Drop for Guard<F> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+fn defer<F: FnOnce()>(cleanup: F) -> Guard<F> {
+    Guard {
+        cleanup: Some(cleanup),
+    }
+}
+
+// The guards are created right after each step, so they undo exactly the
+// steps that have been done. Guards are dropped in reverse order, so the
+// cleanup happens in reverse order as well.
+fn deploy(fail_at: Option<&str>) -> Result<(), String> {
+    let step = |name: &str| -> Result<(), String> {
+        if fail_at == Some(name) {
+            return Err(format!("{} failed", name));
+        }
+        println!("  {}", name);
+        Ok(())
+    };
+
+    step("create directory")?;
+    let directory = defer(|| println!("  cleanup: remove directory"));
+
+    step("copy files to staging")?;
+    let _staging = defer(|| println!("  cleanup: remove staging files"));
+
+    step("restart server")?;
+
+    // Everything worked, so the directory stays. The staging files are
+    // removed either way, since that guard is never dismissed.
+    directory.dismiss();
+    Ok(())
+}
+
+fn main() {
+
+    // ### Scopes
+
+    // Values are dropped at the end of the scope that owns them, in the
+    // reverse order of their declaration. Later values may refer to
+    // earlier ones, so they have to go first.
+    println!("Scopes:");
+    let _outer = Noisy::new("outer");
+    {
+        let _first = Noisy::new("first");
+        let _second = Noisy::new("second");
+        println!("  end of inner scope");
+    }
+    println!("  back in main");
+
+    // ### Fields and Elements
+
+    // A struct runs its own `drop` first, and then drops its fields in the
+    // order of their declaration.
+    println!("Fields:");
+    {
+        let _pair = Pair {
+            first: Noisy::new("left"),
+            second: Noisy::new("right"),
+        };
+    }
+
+    // The elements of a `Vec` are dropped from first to last.
+    println!("Vec elements:");
+    {
+        let mut list = Vec::new();
+        list.push(Noisy::new("one"));
+        list.push(Noisy::new("two"));
+        list.push(Noisy::new("three"));
+    }
+
+    // ### Moves and Temporaries
+
+    // A moved value is dropped by its new owner, and not at the end of the
+    // original scope.
+    println!("Moves:");
+    let moved = Noisy::new("moved");
+    consume(moved);
+    println!("  after consume");
+
+    // A temporary is dropped at the end of the statement that created it.
+    // Binding a value to `_` doesn't bind it at all, so it's dropped right
+    // away too. A name starting with an underscore, like `_kept`, is a real
+    // binding that lives until the end of the scope.
+    println!("Temporaries:");
+    {
+        println!("  name: {}", Noisy::new("temporary").name);
+        let _ = Noisy::new("underscore");
+        let _kept = Noisy::new("kept");
+        println!("  end of scope");
+    }
+
+    // ### Dropping Early
+
+    // `mem::drop` drops a value before the end of its scope. There is no
+    // magic to it: it takes the value by value and does nothing, so the
+    // value is dropped at the end of `mem::drop`, like in `consume`.
+    println!("mem::drop:");
+    let early = Noisy::new("early");
+    mem::drop(early);
+    println!("  after mem::drop");
+
+    // ### Not Dropping
+
+    // `mem::forget` takes ownership of a value without running its
+    // destructor. Its resources are leaked. This is safe, but rarely what
+    // we want.
+    println!("mem::forget:");
+    let forgotten = Noisy::new("forgotten");
+    mem::forget(forgotten);
+    println!("  after mem::forget");
+
+    // `ManuallyDrop` wraps a value and stops it from being dropped
+    // automatically. The value can still be used through the wrapper, and
+    // `ManuallyDrop::into_inner` unwraps it to give it back to the normal
+    // drop rules. This is useful for controlling the drop order of fields
+    // in low-level code.
+    println!("ManuallyDrop:");
+    {
+        let manual = ManuallyDrop::new(Noisy::new("manual"));
+        let unwrapped = ManuallyDrop::new(Noisy::new("unwrapped"));
+        println!("  using {} and {}", manual.name, unwrapped.name);
+        let _unwrapped = ManuallyDrop::into_inner(unwrapped);
+        println!("  end of scope");
+    }
+
+    // ### Scope Guards in Action
+
+    println!("Deploy that succeeds:");
+    println!("  result: {:?}", deploy(None));
+    println!("Deploy that fails:");
+    println!("  result: {:?}", deploy(Some("restart server")));
+
+    println!("End of main");
+}
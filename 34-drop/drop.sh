@@ -0,0 +1,65 @@
+# Language: shell
+$ rustc drop.rs
+$ ./drop
+Scopes:
+  create outer
+  create first
+  create second
+  end of inner scope
+  drop second
+  drop first
+  back in main
+Fields:
+  create left
+  create right
+  drop pair of left and right
+  drop left
+  drop right
+Vec elements:
+  create one
+  create two
+  create three
+  drop one
+  drop two
+  drop three
+Moves:
+  create moved
+  consuming moved
+  drop moved
+  after consume
+Temporaries:
+  create temporary
+  name: temporary
+  drop temporary
+  create underscore
+  drop underscore
+  create kept
+  end of scope
+  drop kept
+mem::drop:
+  create early
+  drop early
+  after mem::drop
+mem::forget:
+  create forgotten
+  after mem::forget
+ManuallyDrop:
+  create manual
+  create unwrapped
+  using manual and unwrapped
+  end of scope
+  drop unwrapped
+Deploy that succeeds:
+  create directory
+  copy files to staging
+  restart server
+  cleanup: remove staging files
+  result: Ok(())
+Deploy that fails:
+  create directory
+  copy files to staging
+  cleanup: remove staging files
+  cleanup: remove directory
+  result: Err("restart server failed")
+End of main
+  drop outer
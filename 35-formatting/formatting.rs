@@ -0,0 +1,166 @@
+// Title: Formatting
+// Language: Rust
+//
+// `println!`, `format!`, `write!` and their relatives all share the
+// formatting syntax of the `std::fmt` module. Each `{}` in the format string
+// is a placeholder, and what's inside the braces controls which argument is
+// printed and how: `{name:>8.2}` prints the argument `name`, right-aligned
+// in 8 columns, with 2 digits after the decimal point.
+//
+// How a value is printed is decided by traits. `{}` uses `Display`, which
+// is meant for users, and `{:?}` uses `Debug`, which is meant for
+// programmers. Both can be implemented by hand.
+
+use std::f64::consts::PI;
+use std::fmt;
+use std::fmt::Write;
+
+// The `Rectangle` from the structs chapter. Instead of deriving `Debug`, we
+// implement both traits ourselves.
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+}
+
+// `Display` has a single method, `fmt`, which writes the value into a
+// `Formatter` with `write!`. The `Formatter` also carries the options of
+// the placeholder, like width and precision, which `fmt` may use or ignore.
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+// `Formatter::debug_struct` builds the same output as `#[derive(Debug)]`,
+// and it supports the pretty-printed `{:#?}` form for free. Here we add the
+// area as an extra field, which a derived implementation can't do.
+impl fmt::Debug for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Rectangle")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("area", &self.area())
+            .finish()
+    }
+}
+
+// A type that respects the width and alignment of the placeholder.
+// `Formatter::pad` applies them to a string, the way `str` itself does.
+struct Label(&'static str);
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.0)
+    }
+}
+
+fn main() {
+
+    // ### Arguments
+
+    // Placeholders take the arguments in order. An index picks a specific
+    // argument, so an argument can be used more than once.
+    let (first, second) = ("first", "second");
+    println!("{} and {}", first, second);
+    println!("{1} and {0}, then {1} again", first, second);
+
+    // Named arguments are given as `name = value`.
+    let place = ("Lyon", "France");
+    println!("{city} is in {country}", city = place.0, country = place.1);
+
+    // A name that isn't passed as an argument is captured from the
+    // surrounding scope. Only plain variable names work here, not
+    // expressions like `rect.width`.
+    let language = "Rust";
+    let version = 2021;
+    println!("{language} edition {version}");
+
+    // ### Width and Alignment
+
+    // After the `:` comes the format spec. A number sets the minimum width.
+    // `<`, `^` and `>` align left, center and right, and a character before
+    // them is used for padding instead of spaces. Strings align left by
+    // default, and numbers right.
+    println!("[{:8}]", "left");
+    println!("[{:8}]", 42);
+    println!("[{:<8}] [{:^8}] [{:>8}]", "left", "center", "right");
+    println!("[{:*^12}]", "stars");
+    println!("[{:-<8}]", Label("label"));
+
+    // The width can come from an argument too, with `$`.
+    let width = 10;
+    println!("[{:>width$}]", "dynamic", width = width);
+    println!("[{:>1$}]", "indexed", width);
+
+    // ### Precision
+
+    // For floats, `.N` sets the number of digits after the decimal point,
+    // rounding the value. For strings, it sets the maximum length.
+    println!("{:.2}", PI);
+    println!("{:.0}", PI);
+    println!("{:.3}", "truncated");
+
+    // Width and precision combine: `{:>8.2}` right-aligns in 8 columns with
+    // 2 decimals. This lines up columns of numbers.
+    let prices = [("Coffee", 3.5), ("Sandwich", 7.25), ("Cake", 12.0)];
+    for (item, price) in prices.iter() {
+        println!("{:<10}{:>8.2}", item, price);
+    }
+
+    // `+` always prints the sign, and `e` uses scientific notation.
+    println!("{:+} {:+}", 5, -5);
+    println!("{:e}", 1234.5);
+
+    // ### Number Bases
+
+    // `b`, `o`, `x` and `X` print integers in binary, octal and hexadecimal.
+    // `#` adds the prefix, and a `0` before the width pads with zeros
+    // instead of spaces, after the sign or prefix.
+    let value = 171;
+    println!("{:b} {:o} {:x} {:X}", value, value, value, value);
+    println!("{:#b} {:#o} {:#x}", value, value, value);
+    println!("{:08b}", 5);
+    println!("{:#06x}", 255);
+    println!("{:05}", -42);
+
+    // ### Display and Debug
+
+    // Our own `Display` and `Debug` implementations are used by `{}` and
+    // `{:?}`, and `{:#?}` prints the `Debug` output over several lines.
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    println!("{}", rect);
+    println!("{:?}", rect);
+    println!("{:#?}", rect);
+
+    // `Rectangle`'s `Display` ignores the width, since it writes with
+    // `write!`. To align it, we format it into a `String` first.
+    println!("[{:>10}]", rect.to_string());
+
+    // ### Writing into a `String`
+
+    // `write!` works with anything that implements `fmt::Write`, like
+    // `String`, so output can be built up piece by piece without allocating
+    // a new `String` for every part like `format!` does. It returns a
+    // `Result`, which never fails for `String`.
+    let mut table = String::new();
+    for (item, price) in prices.iter() {
+        writeln!(table, "{:.<12}{:.>6.2}", item, price).unwrap();
+    }
+    write!(table, "{} items", prices.len()).unwrap();
+    println!("{}", table);
+
+    // ### Escaping Braces
+
+    // Literal braces are written twice.
+    let braces = "{}";
+    println!("{{}} prints as {}", braces);
+}
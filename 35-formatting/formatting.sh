@@ -0,0 +1,40 @@
+# Language: shell
+$ rustc formatting.rs
+$ ./formatting
+first and second
+second and first, then second again
+Lyon is in France
+Rust edition 2021
+[left    ]
+[      42]
+[left    ] [ center ] [   right]
+[***stars****]
+[label---]
+[   dynamic]
+[   indexed]
+3.14
+3
+tru
+Coffee        3.50
+Sandwich      7.25
+Cake         12.00
++5 -5
+1.2345e3
+10101011 253 ab AB
+0b10101011 0o253 0xab
+00000101
+0x00ff
+-0042
+30x50
+Rectangle { width: 30, height: 50, area: 1500 }
+Rectangle {
+    width: 30,
+    height: 50,
+    area: 1500,
+}
+[     30x50]
+Coffee........3.50
+Sandwich......7.25
+Cake.........12.00
+3 items
+{} prints as {}
@@ -0,0 +1,717 @@
+// Generates the static rustbyexample.io site from the chapter directories.
+//
+// Every `NN-topic/` directory holds a `topic.rs` source file and a matching
+// `topic.sh` transcript. Both files are split into segments of prose (comment
+// lines) and code, which are rendered side by side in the "by example" layout.
+// Transcript directives are hidden, except that the input of a command is
+// shown as a heredoc. An index page lists all chapters ordered by their
+// numeric prefix.
+//
+// Usage:
+//
+//     $ cargo run -p tools --bin generate -- [root] [output]
+//
+// `root` defaults to the current directory and `output` to `public`.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const SITE_TITLE: &str = "Rust by Example";
+
+const STYLESHEET: &str = r#"body {
+    margin: 0;
+    font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif;
+    font-size: 15px;
+    line-height: 1.5;
+    color: #252519;
+}
+a { color: #b7410e; }
+h2 { margin: 40px 0 20px 0; }
+h2 a { text-decoration: none; }
+div.index, div.example { width: 960px; margin: 0 auto; }
+div.index ul { list-style: none; padding: 0; }
+table { border-spacing: 0; border-collapse: collapse; width: 100%; }
+table + table { margin-top: 24px; }
+td { vertical-align: top; padding: 0; }
+td.docs { width: 420px; padding: 10px 15px 1px 0; }
+td.code { padding: 8px 0 8px 15px; background: #f8f8f8; }
+td.code.empty { background: none; }
+pre, code { font-family: Menlo, Monaco, Consolas, monospace; font-size: 13px; }
+pre { margin: 0; white-space: pre; }
+td.docs code { background: #f0f0f0; padding: 0 3px; }
+p.file { margin: 24px 0 4px 0; font-weight: bold; }
+p.next { margin: 30px 0 10px 0; }
+p.footer { margin: 40px 0; color: #808080; font-size: 13px; }
+"#;
+
+// A chapter is a single `NN-topic` directory rendered as one page.
+struct Chapter {
+    number: u32,
+    slug: String,
+    title: String,
+    files: Vec<SourceFile>,
+}
+
+// A source file of a chapter, split into prose/code segments.
+struct SourceFile {
+    name: String,
+    segments: Vec<Segment>,
+}
+
+// A segment pairs a block of prose with the code that follows it. Either side
+// may be empty.
+#[derive(Default)]
+struct Segment {
+    docs: Vec<String>,
+    code: Vec<String>,
+}
+
+// The languages that can appear in a chapter, which determines the prefix of
+// the comment lines that hold the prose.
+#[derive(Clone, Copy)]
+enum Language {
+    Rust,
+    Shell,
+}
+
+impl Language {
+    fn from_path(path: &Path) -> Option<Language> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Language::Rust),
+            "sh" => Some(Language::Shell),
+            _ => None,
+        }
+    }
+
+    fn comment(self) -> &'static str {
+        match self {
+            Language::Rust => "//",
+            Language::Shell => "#",
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let root = PathBuf::from(args.first().map(String::as_str).unwrap_or("."));
+    let output = args
+        .get(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("public"));
+
+    if let Err(err) = run(&root, &output) {
+        eprintln!("generate: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(root: &Path, output: &Path) -> io::Result<()> {
+    let chapters = read_chapters(root)?;
+    fs::create_dir_all(output)?;
+    fs::write(output.join("site.css"), STYLESHEET)?;
+    fs::write(output.join("index.html"), render_index(&chapters))?;
+
+    for (i, chapter) in chapters.iter().enumerate() {
+        let page = render_chapter(chapter, chapters.get(i + 1));
+        fs::write(output.join(format!("{}.html", chapter.slug)), page)?;
+    }
+
+    println!(
+        "generated {} chapters into {}",
+        chapters.len(),
+        output.display(),
+    );
+    Ok(())
+}
+
+// Collects every `NN-topic` directory below `root`, ordered by `NN`.
+fn read_chapters(root: &Path) -> io::Result<Vec<Chapter>> {
+    let mut chapters = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let Some((number, slug)) = split_dir_name(&path) else {
+            continue;
+        };
+        chapters.push(read_chapter(&path, number, slug)?);
+    }
+    chapters.sort_by_key(|chapter| chapter.number);
+    Ok(chapters)
+}
+
+// Splits a directory name like `20-error-handling` into its number and slug.
+fn split_dir_name(path: &Path) -> Option<(u32, String)> {
+    let name = path.file_name()?.to_str()?;
+    let (number, slug) = name.split_once('-')?;
+    Some((number.parse().ok()?, slug.to_string()))
+}
+
+fn read_chapter(dir: &Path, number: u32, slug: String) -> io::Result<Chapter> {
+    let main_file = dir.join(format!("{}.rs", slug));
+    let transcript = dir.join(format!("{}.sh", slug));
+
+    // The main source file comes first, followed by the other source files
+    // of a chapter that spans several files, and finally the transcript.
+    let mut modules = Vec::new();
+    find_sources(dir, &mut modules)?;
+    modules.retain(|path| *path != main_file);
+    // Sorting by the path as a string puts `garden.rs` before the files in
+    // `garden/`.
+    modules.sort_by_key(|path| path.to_string_lossy().into_owned());
+
+    let mut paths = vec![main_file.clone()];
+    paths.extend(modules);
+    paths.push(transcript);
+
+    let mut title = None;
+    let mut files = Vec::new();
+    for path in paths.into_iter().filter(|path| path.exists()) {
+        let (file_title, file) = read_source_file(dir, &path)?;
+        if path == main_file {
+            title = file_title;
+        }
+        files.push(file);
+    }
+
+    if files.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} contains no {}.rs or {}.sh", dir.display(), slug, slug),
+        ));
+    }
+
+    Ok(Chapter {
+        number,
+        title: title.unwrap_or_else(|| slug.clone()),
+        slug,
+        files,
+    })
+}
+
+// Collects the `.rs` files in `dir` and its subdirectories.
+fn find_sources(dir: &Path, sources: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_sources(&path, sources)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            sources.push(path);
+        }
+    }
+    Ok(())
+}
+
+// Reads a source file of the chapter in `dir`. The file is named by its path
+// relative to the chapter, like `garden/vegetables.rs`.
+fn read_source_file(dir: &Path, path: &Path) -> io::Result<(Option<String>, SourceFile)> {
+    let language = Language::from_path(path).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a .rs or .sh file", path.display()),
+        )
+    })?;
+    let source = fs::read_to_string(path)?;
+    let (title, segments) = parse_segments(&source, language);
+    let name = path
+        .strip_prefix(dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned();
+    Ok((title, SourceFile { name, segments }))
+}
+
+// Splits a source file into prose/code segments. The `Title:` and `Language:`
+// header lines are not rendered; the title is returned separately.
+fn parse_segments(source: &str, language: Language) -> (Option<String>, Vec<Segment>) {
+    let comment = language.comment();
+    let mut title = None;
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut last_was_code = false;
+    let mut in_stdin = false;
+
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        let is_docs = trimmed.starts_with(comment) && !is_code_comment(trimmed);
+
+        if is_docs {
+            let text = trimmed[comment.len()..].trim();
+            if let Some(value) = text.strip_prefix("Title:") {
+                title = Some(value.trim().to_string());
+                continue;
+            }
+            if text.starts_with("Language:") {
+                continue;
+            }
+            // The input of a command, given in a `{stdin}` block, stays
+            // visible so that readers can run the command themselves. It's
+            // rendered as a heredoc: `<<'EOF'` is added to the command, which
+            // the block follows directly, and `EOF` closes the input.
+            if text == "{stdin}" {
+                let command = segments
+                    .iter_mut()
+                    .rev()
+                    .find_map(|segment| segment.code.last_mut());
+                if let Some(command) = command {
+                    command.push_str(" <<'EOF'");
+                    in_stdin = true;
+                }
+                continue;
+            }
+            if text == "{end}" && in_stdin {
+                if segments.is_empty() {
+                    segments.push(Segment::default());
+                }
+                segments.last_mut().unwrap().code.push("EOF".to_string());
+                last_was_code = true;
+                in_stdin = false;
+                continue;
+            }
+            if is_directive(text) {
+                continue;
+            }
+            if segments.is_empty() || last_was_code {
+                segments.push(Segment::default());
+            }
+            segments.last_mut().unwrap().docs.push(text.to_string());
+            last_was_code = false;
+        } else {
+            if segments.is_empty() {
+                segments.push(Segment::default());
+            }
+            segments.last_mut().unwrap().code.push(line.to_string());
+            last_was_code = true;
+        }
+    }
+
+    for segment in &mut segments {
+        trim_blank_lines(&mut segment.docs);
+        trim_blank_lines(&mut segment.code);
+    }
+    segments.retain(|segment| !segment.docs.is_empty() || !segment.code.is_empty());
+
+    (title, segments)
+}
+
+// Inner attributes and doc comments are part of the code, not prose.
+fn is_code_comment(line: &str) -> bool {
+    line.starts_with("///") || line.starts_with("//!") || line.starts_with("#!")
+}
+
+// Transcript directives such as `# {unordered}` are only read by the
+// verifier, so they are not rendered.
+fn is_directive(text: &str) -> bool {
+    text.starts_with('{') && text.contains('}')
+}
+
+fn trim_blank_lines(lines: &mut Vec<String>) {
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    let leading = lines
+        .iter()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    lines.drain(..leading);
+}
+
+fn render_index(chapters: &[Chapter]) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "<div class=\"index\">");
+    let _ = writeln!(body, "<h2>{}</h2>", SITE_TITLE);
+    let _ = writeln!(body, "<ul>");
+    for chapter in chapters {
+        let _ = writeln!(
+            body,
+            "<li><a href=\"{}.html\">{}</a></li>",
+            chapter.slug,
+            render_inline(&chapter.title),
+        );
+    }
+    let _ = writeln!(body, "</ul>");
+    let _ = writeln!(body, "</div>");
+    render_page(SITE_TITLE, &body)
+}
+
+fn render_chapter(chapter: &Chapter, next: Option<&Chapter>) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "<div class=\"example\" id=\"{}\">", chapter.slug);
+    let _ = writeln!(
+        body,
+        "<h2><a href=\"index.html\">{}</a>: {}</h2>",
+        SITE_TITLE,
+        render_inline(&chapter.title),
+    );
+
+    // When a chapter spans several source files, each file is introduced by
+    // its name.
+    let sources = chapter
+        .files
+        .iter()
+        .filter(|file| file.name.ends_with(".rs"))
+        .count();
+    for file in &chapter.files {
+        if sources > 1 {
+            let _ = writeln!(body, "<p class=\"file\">{}</p>", escape_html(&file.name));
+        }
+        render_file(&mut body, file);
+    }
+
+    if let Some(next) = next {
+        let _ = writeln!(
+            body,
+            "<p class=\"next\">Next example: <a href=\"{}.html\">{}</a>.</p>",
+            next.slug,
+            render_inline(&next.title),
+        );
+    }
+    let _ = writeln!(body, "</div>");
+
+    let title = format!("{}: {}", SITE_TITLE, strip_markup(&chapter.title));
+    render_page(&title, &body)
+}
+
+fn render_file(body: &mut String, file: &SourceFile) {
+    let _ = writeln!(body, "<table data-file=\"{}\">", escape_html(&file.name));
+    for segment in &file.segments {
+        let _ = writeln!(body, "<tr>");
+        let _ = writeln!(
+            body,
+            "<td class=\"docs\">{}</td>",
+            render_docs(&segment.docs)
+        );
+        if segment.code.is_empty() {
+            let _ = writeln!(body, "<td class=\"code empty\"></td>");
+        } else {
+            let _ = writeln!(
+                body,
+                "<td class=\"code\"><pre><code>{}</code></pre></td>",
+                escape_html(&segment.code.join("\n")),
+            );
+        }
+        let _ = writeln!(body, "</tr>");
+    }
+    let _ = writeln!(body, "</table>");
+}
+
+fn render_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>{}</title>\n\
+         <link rel=\"stylesheet\" href=\"site.css\">\n\
+         </head>\n\
+         <body>\n\
+         {}\
+         <p class=\"footer\"><a href=\"https://rustbyexample.io\">rustbyexample.io</a></p>\n\
+         </body>\n\
+         </html>\n",
+        escape_html(title),
+        body,
+    )
+}
+
+// Renders the prose of a segment. Blank lines separate paragraphs, `###`
+// lines become headings and `1.` lines become ordered lists; everything else
+// is rendered inline.
+fn render_docs(lines: &[String]) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Vec<String> = Vec::new();
+
+    for line in lines {
+        let line = line.as_str();
+        if line.is_empty() {
+            flush_paragraph(&mut html, &mut paragraph);
+            flush_list(&mut html, &mut list);
+        } else if let Some(level) = heading_level(line) {
+            flush_paragraph(&mut html, &mut paragraph);
+            flush_list(&mut html, &mut list);
+            let text = line[level..].trim();
+            let _ = write!(html, "<h{0}>{1}</h{0}>", level, render_inline(text));
+        } else if let Some(item) = list_item(line) {
+            flush_paragraph(&mut html, &mut paragraph);
+            list.push(item.to_string());
+        } else if let Some(item) = list.last_mut() {
+            // A wrapped line continues the previous list item.
+            item.push(' ');
+            item.push_str(line);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush_paragraph(&mut html, &mut paragraph);
+    flush_list(&mut html, &mut list);
+    html
+}
+
+fn flush_paragraph(html: &mut String, paragraph: &mut Vec<&str>) {
+    if !paragraph.is_empty() {
+        let _ = write!(html, "<p>{}</p>", render_inline(&paragraph.join(" ")));
+        paragraph.clear();
+    }
+}
+
+fn flush_list(html: &mut String, list: &mut Vec<String>) {
+    if !list.is_empty() {
+        html.push_str("<ol>");
+        for item in list.iter() {
+            let _ = write!(html, "<li>{}</li>", render_inline(item));
+        }
+        html.push_str("</ol>");
+        list.clear();
+    }
+}
+
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = &line[level..];
+    if (1..=6).contains(&level) && rest.starts_with(' ') {
+        Some(level)
+    } else {
+        None
+    }
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    let (number, rest) = line.split_once(". ")?;
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+// Renders the inline markup used in the prose: `code`, **bold**, *emphasis*
+// and [links](url). Unmatched markers are rendered as plain text.
+fn render_inline(text: &str) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                let code = &rest[1..1 + end];
+                let _ = write!(html, "<code>{}</code>", escape_html(code));
+                rest = &rest[end + 2..];
+                continue;
+            }
+        } else if let Some(inner) = rest.strip_prefix("**") {
+            if let Some(end) = inner.find("**").filter(|&end| end > 0) {
+                let _ = write!(html, "<strong>{}</strong>", render_inline(&inner[..end]));
+                rest = &inner[end + 2..];
+                continue;
+            }
+        } else if c == '*' {
+            if let Some(end) = rest[1..].find('*').filter(|&end| end > 0) {
+                let _ = write!(html, "<em>{}</em>", render_inline(&rest[1..1 + end]));
+                rest = &rest[end + 2..];
+                continue;
+            }
+        } else if c == '[' {
+            if let Some((label, url, len)) = parse_link(rest) {
+                if url.is_empty() {
+                    html.push_str(&render_inline(label));
+                } else {
+                    let _ = write!(
+                        html,
+                        "<a href=\"{}\">{}</a>",
+                        escape_html(url),
+                        render_inline(label),
+                    );
+                }
+                rest = &rest[len..];
+                continue;
+            }
+        }
+
+        html.push_str(&escape_html(&rest[..c.len_utf8()]));
+        rest = &rest[c.len_utf8()..];
+    }
+    html
+}
+
+// Parses a `[label](url)` link at the start of `text`, returning the label,
+// the url and the number of bytes consumed.
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let url_end = text[label_end + 2..].find(')')? + label_end + 2;
+    let label = &text[1..label_end];
+    if label.contains('[') {
+        return None;
+    }
+    Some((label, &text[label_end + 2..url_end], url_end + 1))
+}
+
+// Removes the inline markup from a title, for use in the `<title>` element.
+fn strip_markup(text: &str) -> String {
+    text.chars().filter(|c| !matches!(c, '*' | '`')).collect()
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn segments_pair_prose_with_the_following_code() {
+        let source =
+            "// Intro.\n\nfn main() {\n    // Docs.\n    // More docs.\n    let x = 1;\n}\n";
+        let (_, segments) = parse_segments(source, Language::Rust);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].docs, lines(&["Intro."]));
+        assert_eq!(segments[0].code, lines(&["fn main() {"]));
+        assert_eq!(segments[1].docs, lines(&["Docs.", "More docs."]));
+        assert_eq!(segments[1].code, lines(&["    let x = 1;", "}"]));
+    }
+
+    #[test]
+    fn segments_keep_doc_comments_and_attributes_as_code() {
+        let source = "#![allow(unused)]\n/// A function.\nfn f() {}\n";
+        let (_, segments) = parse_segments(source, Language::Rust);
+        assert_eq!(segments.len(), 1);
+        assert!(segments[0].docs.is_empty());
+        assert_eq!(segments[0].code.len(), 3);
+    }
+
+    #[test]
+    fn title_and_language_lines_are_stripped() {
+        let source = "// Title: Hello World\n// Language: Rust\n//\n// Prose.\nfn main() {}\n";
+        let (title, segments) = parse_segments(source, Language::Rust);
+        assert_eq!(title.as_deref(), Some("Hello World"));
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].docs, lines(&["Prose."]));
+
+        let (title, segments) = parse_segments("# Language: shell\n$ ./a\n", Language::Shell);
+        assert_eq!(title, None);
+        assert!(segments[0].docs.is_empty());
+        assert_eq!(segments[0].code, lines(&["$ ./a"]));
+    }
+
+    #[test]
+    fn directives_are_hidden() {
+        let source = "$ ./a\n# {unordered}\nx\ny\n# {end}\n# {match} z*\nzz\n# {stderr}\n\
+                      error\n# {exit} 1\n";
+        let (_, segments) = parse_segments(source, Language::Shell);
+        assert_eq!(segments.len(), 1);
+        assert!(segments[0].docs.is_empty());
+        assert_eq!(segments[0].code, lines(&["$ ./a", "x", "y", "zz", "error"]));
+    }
+
+    #[test]
+    fn stdin_is_shown_as_a_heredoc() {
+        let source = "# Pipe it.\n$ ./io\n# {stdin}\nhello\n# {end}\nHELLO\n";
+        let (_, segments) = parse_segments(source, Language::Shell);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].docs, lines(&["Pipe it."]));
+        assert_eq!(
+            segments[0].code,
+            lines(&["$ ./io <<'EOF'", "hello", "EOF", "HELLO"])
+        );
+    }
+
+    #[test]
+    fn docs_render_paragraphs_headings_and_lists() {
+        let docs = lines(&[
+            "### Heading",
+            "",
+            "A paragraph",
+            "over two lines.",
+            "1. First item",
+            "wrapped.",
+            "2. Second item",
+        ]);
+        assert_eq!(
+            render_docs(&docs),
+            "<h3>Heading</h3><p>A paragraph over two lines.</p>\
+             <ol><li>First item wrapped.</li><li>Second item</li></ol>"
+        );
+    }
+
+    #[test]
+    fn headings_need_a_space_after_the_hashes() {
+        assert_eq!(heading_level("## Title"), Some(2));
+        assert_eq!(heading_level("#hashtag"), None);
+        assert_eq!(heading_level("####### Too deep"), None);
+    }
+
+    #[test]
+    fn list_items_start_with_a_number_and_a_dot() {
+        assert_eq!(list_item("1. First"), Some("First"));
+        assert_eq!(list_item("12. Twelfth"), Some("Twelfth"));
+        assert_eq!(list_item("Version 2. Not a list"), None);
+        assert_eq!(list_item(". Nothing"), None);
+    }
+
+    #[test]
+    fn inline_markup() {
+        assert_eq!(render_inline("`a < b`"), "<code>a &lt; b</code>");
+        assert_eq!(render_inline("**bold**"), "<strong>bold</strong>");
+        assert_eq!(render_inline("*emphasis*"), "<em>emphasis</em>");
+        assert_eq!(
+            render_inline("**`Box`**"),
+            "<strong><code>Box</code></strong>"
+        );
+        assert_eq!(
+            render_inline("`*not emphasis*`"),
+            "<code>*not emphasis*</code>"
+        );
+    }
+
+    #[test]
+    fn unmatched_markers_are_plain_text() {
+        assert_eq!(render_inline("a * b"), "a * b");
+        assert_eq!(render_inline("2 ** 3"), "2 ** 3");
+        assert_eq!(render_inline("**"), "**");
+        assert_eq!(render_inline("`unclosed"), "`unclosed");
+        assert_eq!(render_inline("trailing *"), "trailing *");
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            render_inline("see [the docs](https://doc.rust-lang.org/?a=1&b=2)."),
+            "see <a href=\"https://doc.rust-lang.org/?a=1&amp;b=2\">the docs</a>."
+        );
+        assert_eq!(
+            render_inline("[`fmt`](https://x.io)"),
+            "<a href=\"https://x.io\"><code>fmt</code></a>"
+        );
+        assert_eq!(render_inline("[no url]()"), "no url");
+        assert_eq!(render_inline("[not a link] (x)"), "[not a link] (x)");
+        assert_eq!(render_inline("[unclosed](x"), "[unclosed](x");
+    }
+
+    #[test]
+    fn titles_lose_their_markup() {
+        assert_eq!(strip_markup("The `Drop` *trait*"), "The Drop trait");
+        assert_eq!(
+            escape_html("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,884 @@
+// Verifies that the chapter transcripts still match the code.
+//
+// Every `NN-topic/topic.sh` transcript records the commands to run, prefixed
+// with `$`, each followed by the output it is expected to print. The verifier
+// copies the chapter into a scratch directory, runs the commands there with
+// `sh` and compares what they print against the transcript. Chapters that
+// drifted are reported with a unified diff.
+//
+// Output lines belong to stdout, unless they follow a `# {stderr}`
+// directive. Both streams are compared separately, so the transcript only
+// records the order of the lines within each stream. A command is expected
+// to exit with the status `0`, unless stated otherwise with `# {exit} N`.
+//
+// Output that is not deterministic, such as the iteration order of a
+// `HashMap` or lines printed by several threads, is described with
+// directives on comment lines:
+//
+//     # {unordered}   the lines up to `# {end}` may be printed in any order
+//     # {end}         closes an unordered block
+//     # {match} PAT   the next line is an example; the actual line must
+//                     match the wildcard pattern PAT, where `*` matches any
+//                     run of characters and `?` a single character
+//     # {stderr}      the following lines of output are printed on stderr
+//     # {stdout}      the following lines of output are printed on stdout
+//     # {exit} N      the command exits with the status N
+//     # {stdin}       the lines up to `# {end}` are the input of the command,
+//                     given before any of its output
+//
+// Usage:
+//
+//     $ cargo run -p tools --bin verify -- [root] [chapter...]
+//
+// `root` defaults to the current directory. When chapters are given, e.g.
+// `02-variables`, only those are verified.
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::thread;
+
+// The number of unchanged lines shown around each change in a diff.
+const DIFF_CONTEXT: usize = 3;
+
+// The environment variables passed on to the commands. Everything else is
+// cleared, so that variables like `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+// can't change the output depending on who runs the verifier. These are only
+// the ones needed to find and run the compiler.
+const PASSED_ENV: &[&str] = &[
+    "PATH",
+    "HOME",
+    "TMPDIR",
+    "CARGO_HOME",
+    "RUSTUP_HOME",
+    "RUSTUP_TOOLCHAIN",
+];
+
+// A transcript is the list of commands recorded in a `.sh` file.
+struct Transcript {
+    commands: Vec<Step>,
+}
+
+// A single `$` command, its input, and the output and exit status it is
+// expected to produce.
+struct Step {
+    command: String,
+    stdin: String,
+    stdout: Vec<Block>,
+    stderr: Vec<Block>,
+    status: i32,
+}
+
+impl Step {
+    fn expected(&mut self, stream: Stream) -> &mut Vec<Block> {
+        match stream {
+            Stream::Stdout => &mut self.stdout,
+            Stream::Stderr => &mut self.stderr,
+        }
+    }
+}
+
+// The output stream that the expected lines are printed on.
+#[derive(Clone, Copy)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+// A block of lines in a transcript that is not plain output.
+enum Open {
+    Unordered(Vec<Line>),
+    Stdin,
+}
+
+// The expected output is a sequence of blocks. The lines of an unordered
+// block may be printed in any order.
+enum Block {
+    Line(Line),
+    Unordered(Vec<Line>),
+}
+
+// An expected line of output. When it has a pattern, the text is only an
+// example and the pattern decides which lines match.
+struct Line {
+    text: String,
+    pattern: Option<String>,
+}
+
+impl Line {
+    fn matches(&self, actual: &str) -> bool {
+        match &self.pattern {
+            Some(pattern) => wildcard_match(pattern, actual),
+            None => self.text == actual,
+        }
+    }
+}
+
+// The outcome of verifying one chapter.
+enum Outcome {
+    Pass,
+    Fail(String),
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let root = PathBuf::from(args.first().map(String::as_str).unwrap_or("."));
+    let only: Vec<&str> = args.iter().skip(1).map(String::as_str).collect();
+
+    match run(&root, &only) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("verify: {}", err);
+            process::exit(2);
+        }
+    }
+}
+
+// Verifies the chapters below `root` and returns whether all of them passed.
+fn run(root: &Path, only: &[&str]) -> io::Result<bool> {
+    let mut chapters = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        let Some(name) = chapter_name(&path) else {
+            continue;
+        };
+        if only.is_empty() || only.contains(&name.as_str()) {
+            chapters.push((name, path));
+        }
+    }
+    chapters.sort();
+
+    let mut failed = 0;
+    for (name, dir) in &chapters {
+        match verify_chapter(dir)? {
+            Outcome::Pass => println!("PASS {}", name),
+            Outcome::Fail(report) => {
+                println!("FAIL {}", name);
+                print!("{}", report);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", chapters.len() - failed, failed,);
+    Ok(failed == 0)
+}
+
+// Returns the name of a chapter directory, e.g. `02-variables`.
+fn chapter_name(path: &Path) -> Option<String> {
+    if !path.is_dir() {
+        return None;
+    }
+    let name = path.file_name()?.to_str()?;
+    let (number, _) = name.split_once('-')?;
+    number.parse::<u32>().ok()?;
+    Some(name.to_string())
+}
+
+// Returns the slug of a chapter directory, e.g. `variables`.
+fn chapter_slug(dir: &Path) -> &str {
+    let name = dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    name.split_once('-').map_or(name, |(_, slug)| slug)
+}
+
+fn verify_chapter(dir: &Path) -> io::Result<Outcome> {
+    let slug = chapter_slug(dir);
+    let transcript_path = dir.join(format!("{}.sh", slug));
+    let source = match fs::read_to_string(&transcript_path) {
+        Ok(source) => source,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(Outcome::Fail(format!(
+                "    missing {}\n",
+                transcript_path.display()
+            )));
+        }
+        Err(err) => return Err(err),
+    };
+
+    let transcript = match parse_transcript(&source) {
+        Ok(transcript) => transcript,
+        Err(err) => return Ok(Outcome::Fail(format!("    {}\n", err))),
+    };
+    if transcript.commands.is_empty() {
+        return Ok(Outcome::Fail(
+            "    transcript contains no commands\n".to_string(),
+        ));
+    }
+
+    let scratch = env::temp_dir().join(format!("rbe-verify-{}-{}", process::id(), slug));
+    if scratch.exists() {
+        fs::remove_dir_all(&scratch)?;
+    }
+    copy_dir(dir, &scratch)?;
+
+    let outcome = run_transcript(&transcript, &scratch, &transcript_path);
+    fs::remove_dir_all(&scratch)?;
+    outcome
+}
+
+fn run_transcript(transcript: &Transcript, scratch: &Path, path: &Path) -> io::Result<Outcome> {
+    let mut report = String::new();
+
+    for step in &transcript.commands {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(&step.command)
+            .current_dir(scratch)
+            .env_clear()
+            .envs(
+                PASSED_ENV
+                    .iter()
+                    .filter_map(|&name| env::var_os(name).map(|value| (name, value))),
+            )
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // The input is written from another thread, so that a command that
+        // prints a lot before reading all of it can't block forever.
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let input = step.stdin.clone();
+        let writer = thread::spawn(move || {
+            // A command may exit without reading its input, which is fine.
+            let _ = stdin.write_all(input.as_bytes());
+        });
+        let output = child.wait_with_output()?;
+        let _ = writer.join();
+
+        let streams = [
+            ("stdout", &step.stdout, &output.stdout),
+            ("stderr", &step.stderr, &output.stderr),
+        ];
+        for (name, blocks, captured) in streams {
+            let actual = output_lines(&String::from_utf8_lossy(captured));
+            let expected = expected_lines(blocks);
+            let actual = normalize(blocks, &actual);
+
+            if actual != expected {
+                report.push_str(&unified_diff(
+                    &format!("{} ($ {}, {})", path.display(), step.command, name),
+                    "actual",
+                    &expected,
+                    &actual,
+                ));
+            }
+        }
+        if output.status.code() != Some(step.status) {
+            // A command killed by a signal has no exit status.
+            let actual = match output.status.code() {
+                Some(code) => code.to_string(),
+                None => output.status.to_string(),
+            };
+            report.push_str(&format!(
+                "    $ {}: expected exit status {}, got {}\n",
+                step.command, step.status, actual,
+            ));
+            break;
+        }
+    }
+
+    if report.is_empty() {
+        Ok(Outcome::Pass)
+    } else {
+        Ok(Outcome::Fail(report))
+    }
+}
+
+// Parses a transcript. Lines starting with `#` are comments or directives,
+// lines starting with `$ ` are commands and everything else is output of the
+// preceding command, or its input inside a `{stdin}` block.
+fn parse_transcript(source: &str) -> Result<Transcript, String> {
+    let mut commands: Vec<Step> = Vec::new();
+    let mut open: Option<Open> = None;
+    let mut pattern: Option<String> = None;
+    let mut stream = Stream::Stdout;
+
+    for (number, line) in source.lines().enumerate() {
+        let number = number + 1;
+        if let Some(command) = line.strip_prefix("$ ") {
+            if open.is_some() {
+                return Err(format!("line {}: unclosed block before command", number));
+            }
+            if pattern.is_some() {
+                return Err(unused_pattern(number));
+            }
+            commands.push(Step {
+                command: command.trim().to_string(),
+                stdin: String::new(),
+                stdout: Vec::new(),
+                stderr: Vec::new(),
+                status: 0,
+            });
+            stream = Stream::Stdout;
+        } else if let Some((name, argument)) = parse_directive(line) {
+            let Some(step) = commands.last_mut() else {
+                return Err(format!(
+                    "line {}: directive before the first command",
+                    number
+                ));
+            };
+            // A pattern belongs to the line right after it. Only directives
+            // that don't start or end a block may come in between.
+            if pattern.is_some() && !matches!(name, "stdout" | "stderr" | "exit") {
+                return Err(unused_pattern(number));
+            }
+            match (name, &mut open) {
+                ("unordered", None) => open = Some(Open::Unordered(Vec::new())),
+                // The input comes right after the command, so that it can be
+                // shown as a heredoc on the site.
+                ("stdin", None) => {
+                    if !step.stdin.is_empty() || !step.stdout.is_empty() || !step.stderr.is_empty()
+                    {
+                        return Err(format!(
+                            "line {}: input must come before the output of the command",
+                            number
+                        ));
+                    }
+                    open = Some(Open::Stdin);
+                }
+                ("end", Some(_)) => {
+                    if let Some(Open::Unordered(lines)) = open.take() {
+                        step.expected(stream).push(Block::Unordered(lines));
+                    }
+                }
+                ("match", None | Some(Open::Unordered(_))) if !argument.is_empty() => {
+                    pattern = Some(argument.to_string())
+                }
+                ("stdout", None) => stream = Stream::Stdout,
+                ("stderr", None) => stream = Stream::Stderr,
+                ("exit", None) => match argument.parse() {
+                    Ok(status) => step.status = status,
+                    Err(_) => return Err(format!("line {}: invalid exit status", number)),
+                },
+                _ => return Err(format!("line {}: unexpected directive {}", number, line)),
+            }
+        } else if line.starts_with('#') {
+            continue;
+        } else if let Some(step) = commands.last_mut() {
+            if let Some(Open::Stdin) = open {
+                step.stdin.push_str(line);
+                step.stdin.push('\n');
+                continue;
+            }
+            let line = Line {
+                text: line.trim_end().to_string(),
+                pattern: pattern.take(),
+            };
+            match &mut open {
+                Some(Open::Unordered(lines)) => lines.push(line),
+                _ => step.expected(stream).push(Block::Line(line)),
+            }
+        }
+    }
+
+    if open.is_some() {
+        return Err("unclosed block at the end of the transcript".to_string());
+    }
+    if pattern.is_some() {
+        return Err("`# {match}` at the end of the transcript".to_string());
+    }
+    for step in &mut commands {
+        trim_trailing_blank_blocks(&mut step.stdout);
+        trim_trailing_blank_blocks(&mut step.stderr);
+    }
+    Ok(Transcript { commands })
+}
+
+fn unused_pattern(number: usize) -> String {
+    format!(
+        "line {}: `# {{match}}` is not followed by a line of output",
+        number
+    )
+}
+
+// Trailing blank lines are not visible in a transcript, so they are not
+// expected either.
+fn trim_trailing_blank_blocks(blocks: &mut Vec<Block>) {
+    while let Some(Block::Line(line)) = blocks.last() {
+        if !line.text.is_empty() || line.pattern.is_some() {
+            break;
+        }
+        blocks.pop();
+    }
+}
+
+// Splits a directive like `# {match} PAT` into its name and argument.
+fn parse_directive(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix('#')?.trim_start().strip_prefix('{')?;
+    let (name, argument) = rest.split_once('}')?;
+    Some((name.trim(), argument.trim()))
+}
+
+// Returns the lines of the expected output as they appear in the transcript.
+fn expected_lines(blocks: &[Block]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for block in blocks {
+        match block {
+            Block::Line(line) => lines.push(line.text.clone()),
+            Block::Unordered(block) => lines.extend(block.iter().map(|line| line.text.clone())),
+        }
+    }
+    lines
+}
+
+// Lines up the actual output with the expected blocks. Lines that match a
+// pattern, or that match an unordered block in some order, are replaced by
+// their expected text. The result equals the expected lines exactly when the
+// output matches the transcript, and otherwise diffs cleanly against them.
+fn normalize(blocks: &[Block], actual: &[String]) -> Vec<String> {
+    let mut normalized = Vec::with_capacity(actual.len());
+    let mut rest = actual;
+
+    for block in blocks {
+        let (lines, len) = match block {
+            Block::Line(line) => (std::slice::from_ref(line), 1),
+            Block::Unordered(lines) => (lines.as_slice(), lines.len()),
+        };
+        let taken = &rest[..len.min(rest.len())];
+        if matches_unordered(lines, taken) {
+            normalized.extend(lines.iter().map(|line| line.text.clone()));
+        } else {
+            normalized.extend(taken.iter().cloned());
+        }
+        rest = &rest[taken.len()..];
+    }
+    normalized.extend(rest.iter().cloned());
+    normalized
+}
+
+// Returns whether every expected line can be paired with a distinct actual
+// line that it matches, by finding a perfect bipartite matching.
+fn matches_unordered(lines: &[Line], actual: &[String]) -> bool {
+    if lines.len() != actual.len() {
+        return false;
+    }
+    let mut partners: Vec<Option<usize>> = vec![None; actual.len()];
+    (0..lines.len()).all(|i| {
+        let mut seen = vec![false; actual.len()];
+        find_partner(i, lines, actual, &mut partners, &mut seen)
+    })
+}
+
+// Looks for an actual line for expected line `i`, moving the lines that were
+// paired before to other partners when needed.
+fn find_partner(
+    i: usize,
+    lines: &[Line],
+    actual: &[String],
+    partners: &mut [Option<usize>],
+    seen: &mut [bool],
+) -> bool {
+    for j in 0..actual.len() {
+        if seen[j] || !lines[i].matches(&actual[j]) {
+            continue;
+        }
+        seen[j] = true;
+        let free = match partners[j] {
+            Some(other) => find_partner(other, lines, actual, partners, seen),
+            None => true,
+        };
+        if free {
+            partners[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+// Matches `text` against a wildcard pattern, where `*` matches any run of
+// characters and `?` matches a single character.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // The position after the last `*` and the text position it resumes at.
+    let mut backtrack = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            p += 1;
+            backtrack = Some((p, t));
+        } else if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` swallow one more character and try again.
+            p = star_p;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// Splits captured output into lines. Trailing whitespace is not visible in a
+// transcript, so it is ignored.
+fn output_lines(output: &str) -> Vec<String> {
+    let mut lines: Vec<String> = output
+        .lines()
+        .map(|line| line.trim_end().to_string())
+        .collect();
+    trim_trailing_blank_lines(&mut lines);
+    lines
+}
+
+fn trim_trailing_blank_lines(lines: &mut Vec<String>) {
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+// An edit turning the expected lines into the actual lines.
+#[derive(Clone, Copy, PartialEq)]
+enum Edit {
+    Keep,
+    Remove,
+    Insert,
+}
+
+// Produces a unified diff from `expected` to `actual`, indented so it stands
+// out below the chapter name.
+fn unified_diff(from: &str, to: &str, expected: &[String], actual: &[String]) -> String {
+    let edits = diff_edits(expected, actual);
+    let mut diff = format!("    --- {}\n    +++ {}\n", from, to);
+
+    // Group the changes that are close together into hunks, each with a few
+    // lines of context around them.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, edit) in edits.iter().enumerate() {
+        if *edit == Edit::Keep {
+            continue;
+        }
+        let start = i.saturating_sub(DIFF_CONTEXT);
+        let end = (i + 1 + DIFF_CONTEXT).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        let (old_start, new_start) = positions(&edits[..start]);
+        let (old_len, new_len) = positions(&edits[start..end]);
+        diff.push_str(&format!(
+            "    @@ -{} +{} @@\n",
+            hunk_range(old_start, old_len),
+            hunk_range(new_start, new_len),
+        ));
+
+        let (mut old, mut new) = (old_start, new_start);
+        for edit in &edits[start..end] {
+            match edit {
+                Edit::Keep => {
+                    diff.push_str(&format!("     {}\n", expected[old]));
+                    old += 1;
+                    new += 1;
+                }
+                Edit::Remove => {
+                    diff.push_str(&format!("    -{}\n", expected[old]));
+                    old += 1;
+                }
+                Edit::Insert => {
+                    diff.push_str(&format!("    +{}\n", actual[new]));
+                    new += 1;
+                }
+            }
+        }
+    }
+    diff
+}
+
+// Formats the start and length of one side of a hunk. An empty side refers
+// to the line before it, as in `diff -u`.
+fn hunk_range(start: usize, len: usize) -> String {
+    if len == 0 {
+        format!("{},0", start)
+    } else {
+        format!("{},{}", start + 1, len)
+    }
+}
+
+// Returns how many expected and actual lines the edits cover.
+fn positions(edits: &[Edit]) -> (usize, usize) {
+    let old = edits.iter().filter(|&&edit| edit != Edit::Insert).count();
+    let new = edits.iter().filter(|&&edit| edit != Edit::Remove).count();
+    (old, new)
+}
+
+// Computes the edits through the longest common subsequence of both sides.
+fn diff_edits(expected: &[String], actual: &[String]) -> Vec<Edit> {
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            edits.push(Edit::Keep);
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            edits.push(Edit::Remove);
+            i += 1;
+        } else {
+            edits.push(Edit::Insert);
+            j += 1;
+        }
+    }
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact(text: &str) -> Line {
+        Line {
+            text: text.to_string(),
+            pattern: None,
+        }
+    }
+
+    fn pattern(pattern: &str) -> Line {
+        Line {
+            text: String::new(),
+            pattern: Some(pattern.to_string()),
+        }
+    }
+
+    fn strings(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn wildcard_matches_literal_text() {
+        assert!(wildcard_match("abc", "abc"));
+        assert!(!wildcard_match("abc", "abd"));
+        assert!(!wildcard_match("abc", "ab"));
+        assert!(!wildcard_match("ab", "abc"));
+    }
+
+    #[test]
+    fn wildcard_empty_pattern_only_matches_empty_text() {
+        assert!(wildcard_match("", ""));
+        assert!(!wildcard_match("", "a"));
+    }
+
+    #[test]
+    fn wildcard_star_matches_any_run() {
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("**", ""));
+        assert!(wildcard_match("a*", "a"));
+        assert!(wildcard_match("a*", "abc"));
+        assert!(!wildcard_match("a*", "ba"));
+        assert!(wildcard_match("*c", "abc"));
+        assert!(!wildcard_match("*c", "abcd"));
+    }
+
+    #[test]
+    fn wildcard_star_backtracks() {
+        assert!(wildcard_match("a*b*c", "aXbYbc"));
+        assert!(wildcard_match("*ab", "aaab"));
+        assert!(!wildcard_match("a*b*c", "aXbYb"));
+        assert!(wildcard_match(
+            "thread 'main'* panicked at",
+            "thread 'main' (7) panicked at"
+        ));
+    }
+
+    #[test]
+    fn wildcard_question_mark_matches_one_char() {
+        assert!(wildcard_match("a?c", "abc"));
+        assert!(wildcard_match("a?c", "aéc"));
+        assert!(!wildcard_match("a?c", "ac"));
+        assert!(!wildcard_match("ab?", "ab"));
+        assert!(wildcard_match("?*", "x"));
+        assert!(!wildcard_match("?*", ""));
+    }
+
+    #[test]
+    fn unordered_accepts_any_order() {
+        let lines = [exact("a"), exact("b"), exact("c")];
+        assert!(matches_unordered(&lines, &strings(&["c", "a", "b"])));
+        assert!(!matches_unordered(&lines, &strings(&["c", "a", "d"])));
+    }
+
+    #[test]
+    fn unordered_needs_the_same_number_of_lines() {
+        let lines = [exact("a"), exact("b")];
+        assert!(!matches_unordered(&lines, &strings(&["a"])));
+        assert!(!matches_unordered(&lines, &strings(&["a", "b", "b"])));
+        assert!(matches_unordered(&[], &[]));
+    }
+
+    #[test]
+    fn unordered_pairs_each_actual_line_once() {
+        let lines = [exact("a"), exact("a")];
+        assert!(matches_unordered(&lines, &strings(&["a", "a"])));
+        assert!(!matches_unordered(&lines, &strings(&["a", "b"])));
+    }
+
+    #[test]
+    fn unordered_moves_earlier_pairs_to_fit_later_lines() {
+        // The wildcard first takes "a", and has to give it up for the exact
+        // line, which can only match "a".
+        let lines = [pattern("*"), exact("a")];
+        assert!(matches_unordered(&lines, &strings(&["a", "b"])));
+        let lines = [pattern("?"), pattern("a*"), exact("ab")];
+        assert!(matches_unordered(&lines, &strings(&["ab", "ac", "x"])));
+    }
+
+    fn parse(source: &str) -> Vec<Step> {
+        parse_transcript(source).unwrap().commands
+    }
+
+    fn parse_error(source: &str) -> String {
+        parse_transcript(source).err().unwrap()
+    }
+
+    #[test]
+    fn stream_directives_switch_the_expected_stream() {
+        let steps = parse("$ a\nout 1\n# {stderr}\nerr 1\n# {stdout}\nout 2\n");
+        assert_eq!(
+            expected_lines(&steps[0].stdout),
+            strings(&["out 1", "out 2"])
+        );
+        assert_eq!(expected_lines(&steps[0].stderr), strings(&["err 1"]));
+    }
+
+    #[test]
+    fn stream_resets_to_stdout_at_each_command() {
+        let steps = parse("$ a\n# {stderr}\nerr\n$ b\nout\n");
+        assert_eq!(expected_lines(&steps[0].stdout), strings(&[]));
+        assert_eq!(expected_lines(&steps[0].stderr), strings(&["err"]));
+        assert_eq!(expected_lines(&steps[1].stdout), strings(&["out"]));
+        assert_eq!(expected_lines(&steps[1].stderr), strings(&[]));
+    }
+
+    #[test]
+    fn exit_sets_the_expected_status() {
+        let steps = parse("$ a\n# {exit} 101\n$ b\n");
+        assert_eq!(steps[0].status, 101);
+        assert_eq!(steps[1].status, 0);
+    }
+
+    #[test]
+    fn exit_rejects_a_non_numeric_status() {
+        assert_eq!(
+            parse_error("$ a\n# {exit} two\n"),
+            "line 2: invalid exit status"
+        );
+        assert_eq!(
+            parse_error("$ a\n# {exit}\n"),
+            "line 2: invalid exit status"
+        );
+    }
+
+    #[test]
+    fn stdin_block_is_the_input_of_the_command() {
+        let steps = parse("$ a\n# {stdin}\nhello\n\nworld\n# {end}\nHELLO\n");
+        assert_eq!(steps[0].stdin, "hello\n\nworld\n");
+        assert_eq!(expected_lines(&steps[0].stdout), strings(&["HELLO"]));
+    }
+
+    #[test]
+    fn stdin_block_must_be_closed() {
+        assert_eq!(
+            parse_error("$ a\n# {stdin}\nhello\n"),
+            "unclosed block at the end of the transcript"
+        );
+        assert_eq!(
+            parse_error("$ a\n# {stdin}\nhello\n$ b\n"),
+            "line 4: unclosed block before command"
+        );
+    }
+
+    #[test]
+    fn stdin_block_must_come_before_the_output() {
+        assert_eq!(
+            parse_error("$ a\nout\n# {stdin}\nhello\n# {end}\n"),
+            "line 3: input must come before the output of the command"
+        );
+    }
+
+    #[test]
+    fn match_applies_to_the_next_line() {
+        let steps = parse("$ a\n# {match} id *\n# {stderr}\nid 7\n");
+        let Block::Line(line) = &steps[0].stderr[0] else {
+            panic!("expected a line");
+        };
+        assert_eq!(line.pattern.as_deref(), Some("id *"));
+        assert!(line.matches("id 42"));
+    }
+
+    #[test]
+    fn match_must_be_used_before_the_next_command() {
+        assert_eq!(
+            parse_error("$ a\n# {match} *\n$ b\nout\n"),
+            "line 3: `# {match}` is not followed by a line of output"
+        );
+        assert_eq!(
+            parse_error("$ a\n# {match} *\n"),
+            "`# {match}` at the end of the transcript"
+        );
+    }
+
+    #[test]
+    fn match_must_be_used_inside_its_block() {
+        assert_eq!(
+            parse_error("$ a\n# {unordered}\n# {match} *\n# {end}\nout\n"),
+            "line 4: `# {match}` is not followed by a line of output"
+        );
+        assert_eq!(
+            parse_error("$ a\n# {stdin}\n# {match} *\nin\n# {end}\n"),
+            "line 3: unexpected directive # {match} *"
+        );
+    }
+
+    #[test]
+    fn normalize_handles_short_output() {
+        let blocks = [
+            Block::Line(exact("first")),
+            Block::Unordered(vec![exact("a"), exact("b")]),
+        ];
+        let actual = strings(&["first", "b"]);
+        assert_eq!(normalize(&blocks, &actual), actual);
+        assert_eq!(
+            normalize(&blocks, &strings(&["first", "b", "a"])),
+            strings(&["first", "a", "b"])
+        );
+    }
+}